@@ -0,0 +1,316 @@
+//! Graph algorithms.
+use std::collections::{BinaryHeap, VecDeque};
+use std::error::Error;
+use std::fmt;
+use std::iter;
+
+use graph::{DefaultIx, EdgeIndex, EdgeType, Graph, IndexType, Neighbors, NodeIndex};
+use Direction::Outgoing;
+
+/// Error returned by [`zero_one_bfs`](fn.zero_one_bfs.html) for an edge
+/// whose weight is neither `0` nor `1`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidWeight<Ix: IndexType = DefaultIx> {
+    /// The offending edge.
+    pub edge: EdgeIndex<Ix>,
+    /// Its weight.
+    pub weight: u8,
+}
+
+impl<Ix: IndexType> fmt::Display for InvalidWeight<Ix> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "edge {} has weight {}, expected 0 or 1",
+            self.edge.index(),
+            self.weight
+        )
+    }
+}
+
+impl<Ix: IndexType> Error for InvalidWeight<Ix> {}
+
+/// Compute the shortest distances from `start` in a graph whose edge
+/// weights are all `0` or `1`.
+///
+/// Works like a breadth-first search on a deque: nodes reached by a
+/// weight `0` edge are pushed to the front, nodes reached by a weight `1`
+/// edge to the back. Returns the distance of every node by node index, or
+/// `None` if it can't be reached.
+///
+/// ```
+/// use graphs::*;
+/// use graphs::algo::zero_one_bfs;
+///
+/// let mut g = DiGraph::<(), u8>::new();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// g.add_edge(a, b, 1);
+/// g.add_edge(a, c, 1);
+/// g.add_edge(c, b, 0);
+/// assert_eq!(zero_one_bfs(&g, a), Ok(vec![Some(0), Some(1), Some(1)]));
+/// assert_eq!(zero_one_bfs(&g, b), Ok(vec![None, Some(0), None]));
+/// ```
+///
+/// Computes in **O(|V| + |E|)** time. Returns an error, before searching,
+/// if any edge has another weight.
+///
+/// **Panics** if `start` doesn't exist.
+pub fn zero_one_bfs<N, Ty, Ix>(
+    graph: &Graph<N, u8, Ty, Ix>,
+    start: NodeIndex<Ix>,
+) -> Result<Vec<Option<u32>>, InvalidWeight<Ix>>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    if let Some(e) = graph.edge_references().find(|e| *e.weight() > 1) {
+        return Err(InvalidWeight {
+            edge: e.id(),
+            weight: *e.weight(),
+        });
+    }
+    let mut dist = vec![None; graph.node_count()];
+    dist[start.index()] = Some(0);
+    let mut deque = VecDeque::new();
+    deque.push_back(start);
+    while let Some(a) = deque.pop_front() {
+        // every node in the deque has a distance
+        let d = dist[a.index()].unwrap();
+        for (b, &w) in graph.neighbors_weighted(a, Outgoing) {
+            let candidate = d + u32::from(w);
+            if dist[b.index()].is_some_and(|old| old <= candidate) {
+                continue;
+            }
+            dist[b.index()] = Some(candidate);
+            if w == 0 {
+                deque.push_front(b);
+            } else {
+                deque.push_back(b);
+            }
+        }
+    }
+    Ok(dist)
+}
+
+/// Find a path from `s` to `t` that maximises its smallest edge weight,
+/// e.g. the route with the highest bandwidth when the weights are link
+/// capacities.
+///
+/// Returns the bottleneck weight and the nodes of the path, or `None` if
+/// `t` can't be reached. The path from `s` to itself is `[s]` with
+/// bottleneck `u64::MAX`.
+///
+/// ```
+/// use graphs::*;
+/// use graphs::algo::widest_path;
+///
+/// let mut g = UnGraph::<(), u64>::new_undirected();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// g.add_edge(a, c, 2);
+/// g.add_edge(a, b, 10);
+/// g.add_edge(b, c, 5);
+/// assert_eq!(widest_path(&g, a, c), Some((5, vec![a, b, c])));
+/// ```
+///
+/// Computes in **O((|V| + |E|) log |V|)** time, like Dijkstra's algorithm.
+///
+/// **Panics** if `s` or `t` don't exist.
+pub fn widest_path<N, Ty, Ix>(
+    graph: &Graph<N, u64, Ty, Ix>,
+    s: NodeIndex<Ix>,
+    t: NodeIndex<Ix>,
+) -> Option<(u64, Vec<NodeIndex<Ix>>)>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    bottleneck_path(graph, s, t, true)
+}
+
+/// Find a path from `s` to `t` that minimises its largest edge weight.
+///
+/// Returns the largest weight on the path and its nodes, or `None` if `t`
+/// can't be reached. The path from `s` to itself is `[s]` with weight `0`.
+///
+/// Computes in **O((|V| + |E|) log |V|)** time, like Dijkstra's algorithm.
+///
+/// **Panics** if `s` or `t` don't exist.
+pub fn minimax_path<N, Ty, Ix>(
+    graph: &Graph<N, u64, Ty, Ix>,
+    s: NodeIndex<Ix>,
+    t: NodeIndex<Ix>,
+) -> Option<(u64, Vec<NodeIndex<Ix>>)>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    bottleneck_path(graph, s, t, false)
+}
+
+/// Dijkstra's algorithm with `min` (`widest`) or `max` in place of `+`.
+fn bottleneck_path<N, Ty, Ix>(
+    graph: &Graph<N, u64, Ty, Ix>,
+    s: NodeIndex<Ix>,
+    t: NodeIndex<Ix>,
+    widest: bool,
+) -> Option<(u64, Vec<NodeIndex<Ix>>)>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    assert!(
+        s.index() < graph.node_count() && t.index() < graph.node_count(),
+        "bottleneck path: node out of bounds"
+    );
+    // the heap pops the largest rank first, which is the best value
+    let rank = |value: u64| if widest { value } else { !value };
+    let mut best = vec![None; graph.node_count()];
+    let mut predecessor = vec![None; graph.node_count()];
+    let mut done = vec![false; graph.node_count()];
+    let start = if widest { u64::MAX } else { 0 };
+    best[s.index()] = Some(start);
+    let mut heap = BinaryHeap::new();
+    heap.push((rank(start), s.index()));
+    while let Some((_, i)) = heap.pop() {
+        if done[i] {
+            continue;
+        }
+        done[i] = true;
+        if i == t.index() {
+            break;
+        }
+        let value = best[i].unwrap();
+        for (b, &w) in graph.neighbors_weighted(NodeIndex::new(i), Outgoing) {
+            let candidate = if widest { value.min(w) } else { value.max(w) };
+            let j = b.index();
+            if done[j] || best[j].is_some_and(|old| rank(old) >= rank(candidate)) {
+                continue;
+            }
+            best[j] = Some(candidate);
+            predecessor[j] = Some(i);
+            heap.push((rank(candidate), j));
+        }
+    }
+
+    let value = best[t.index()]?;
+    let mut path = vec![t];
+    let mut i = t.index();
+    while let Some(p) = predecessor[i] {
+        path.push(NodeIndex::new(p));
+        i = p;
+    }
+    path.reverse();
+    Some((value, path))
+}
+
+/// Return an iterator over all simple paths from `from` to `to`, each as
+/// the list of its nodes.
+///
+/// Only paths with at least `min_len` and, if given, at most `max_len`
+/// intermediate nodes are yielded; the bounds are enforced while searching,
+/// so longer paths are never explored. The paths are found lazily by a
+/// depth-first search, so taking the first few is cheap even if there are
+/// exponentially many. There are no paths from a node to itself.
+///
+/// ```
+/// use graphs::*;
+/// use graphs::algo::all_simple_paths;
+///
+/// let mut g = UnGraph::<(), ()>::new_undirected();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// g.extend_with_edges(&[(a, b), (b, c), (a, c)]);
+/// let paths: Vec<_> = all_simple_paths(&g, a, c, 0, None).collect();
+/// assert_eq!(paths, vec![vec![a, c], vec![a, b, c]]);
+/// ```
+///
+/// With parallel edges a path is yielded once per edge it could take.
+///
+/// **Panics** if `from` doesn't exist.
+pub fn all_simple_paths<N, E, Ty, Ix>(
+    graph: &Graph<N, E, Ty, Ix>,
+    from: NodeIndex<Ix>,
+    to: NodeIndex<Ix>,
+    min_len: usize,
+    max_len: Option<usize>,
+) -> SimplePaths<'_, N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    let mut on_path = vec![false; graph.node_count()];
+    on_path[from.index()] = true;
+    SimplePaths {
+        graph,
+        to,
+        min_len,
+        max_len,
+        path: vec![from],
+        on_path,
+        stack: vec![graph.neighbors(from)],
+    }
+}
+
+/// Iterator over the simple paths between two nodes.
+///
+/// Created with [`all_simple_paths`](fn.all_simple_paths.html).
+pub struct SimplePaths<'a, N: 'a, E: 'a, Ty: 'a, Ix: 'a + IndexType> {
+    graph: &'a Graph<N, E, Ty, Ix>,
+    to: NodeIndex<Ix>,
+    min_len: usize,
+    max_len: Option<usize>,
+    /// The current path, starting at `from`.
+    path: Vec<NodeIndex<Ix>>,
+    on_path: Vec<bool>,
+    /// The neighbors left to try for every node of `path`.
+    stack: Vec<Neighbors<'a, E, Ix>>,
+}
+
+impl<'a, N, E, Ty, Ix> Iterator for SimplePaths<'a, N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Item = Vec<NodeIndex<Ix>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(neighbors) = self.stack.last_mut() {
+            // the nodes of `path` without `from`
+            let intermediate = self.path.len() - 1;
+            let child = match neighbors.next() {
+                Some(child) => child,
+                None => {
+                    self.stack.pop();
+                    let last = self.path.pop().unwrap();
+                    self.on_path[last.index()] = false;
+                    continue;
+                }
+            };
+            if child == self.to {
+                if intermediate >= self.min_len && !self.on_path[child.index()] {
+                    let mut path = self.path.clone();
+                    path.push(child);
+                    return Some(path);
+                }
+            } else if !self.on_path[child.index()]
+                && self.max_len.is_none_or(|max| intermediate < max)
+            {
+                self.on_path[child.index()] = true;
+                self.path.push(child);
+                self.stack.push(self.graph.neighbors(child));
+            }
+        }
+        None
+    }
+}
+
+impl<'a, N, E, Ty, Ix> iter::FusedIterator for SimplePaths<'a, N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+}
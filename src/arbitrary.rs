@@ -0,0 +1,55 @@
+//! `quickcheck::Arbitrary` for `Graph`, enabled by the `quickcheck` feature.
+use quickcheck::{Arbitrary, Gen};
+
+use graph::{EdgeIndex, EdgeType, Graph, IndexType, NodeIndex};
+
+/// Generate a graph with up to `g.size()` nodes and a random number of
+/// edges between them, possibly including self-loops and parallel edges.
+///
+/// Shrinking first removes single edges, then single nodes together with
+/// their edges, so every shrunk graph is a valid subgraph of the original.
+impl<N, E, Ty, Ix> Arbitrary for Graph<N, E, Ty, Ix>
+where
+    N: Arbitrary,
+    E: Arbitrary,
+    Ty: EdgeType + 'static,
+    Ix: IndexType,
+{
+    fn arbitrary(g: &mut Gen) -> Self {
+        // the largest index is reserved for `end()`
+        let limit = <Ix as IndexType>::max().index();
+        let nodes = usize::arbitrary(g) % (g.size().min(limit) + 1);
+        let mut graph = Graph::with_capacity(nodes, 0);
+        for _ in 0..nodes {
+            graph.add_node(N::arbitrary(g));
+        }
+        if nodes == 0 {
+            return graph;
+        }
+        let edges = usize::arbitrary(g) % ((nodes * nodes).min(limit) + 1);
+        for _ in 0..edges {
+            let a = NodeIndex::new(usize::arbitrary(g) % nodes);
+            let b = NodeIndex::new(usize::arbitrary(g) % nodes);
+            graph.add_edge(a, b, E::arbitrary(g));
+        }
+        graph
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        // every graph with one edge removed, then every graph with one node
+        // and its edges removed, both starting from the last index
+        let graph = self.clone();
+        let fewer_edges = (0..self.edge_count()).rev().map(move |i| {
+            let mut h = graph.clone();
+            h.remove_edge(EdgeIndex::new(i));
+            h
+        });
+        let graph = self.clone();
+        let fewer_nodes = (0..self.node_count()).rev().map(move |i| {
+            let mut h = graph.clone();
+            h.remove_node(NodeIndex::new(i));
+            h
+        });
+        Box::new(fewer_edges.chain(fewer_nodes))
+    }
+}
@@ -0,0 +1,172 @@
+//! Compact binary storage for `Graph`, enabled by the `bin` feature.
+//!
+//! The format is a header of the magic number `0x47524150` ("GRAP", big
+//! endian) and a version byte, followed by the node count as a little endian
+//! `u32`, the node data, the edge count as a little endian `u32` and one
+//! `(source, target, weight)` triple per edge. Node indices are little endian
+//! `u32`s, node data and edge weights are encoded with `bincode`.
+//!
+//! The edge type isn't stored, it is up to the reader to pick the right one.
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Read, Write};
+
+use bincode;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use graph::{EdgeType, Graph, IndexType, NodeIndex};
+
+/// Magic number at the start of every binary graph.
+pub const MAGIC: u32 = 0x4752_4150;
+/// Version of the format written by `write_binary`.
+pub const VERSION: u8 = 1;
+
+/// Error returned when writing or reading a binary graph.
+#[derive(Debug)]
+pub enum BinaryError {
+    /// The underlying reader or writer failed.
+    Io(io::Error),
+    /// Node data or an edge weight could not be encoded or decoded.
+    Encoding(bincode::Error),
+    /// The input doesn't start with `MAGIC`.
+    BadMagic(u32),
+    /// The input was written by an unknown version of the format.
+    UnsupportedVersion(u8),
+    /// The graph has too many nodes or edges for the format or its index type.
+    TooLarge,
+    /// An edge refers to a node that doesn't exist.
+    InvalidEdge(u32, u32),
+}
+
+impl fmt::Display for BinaryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            BinaryError::Io(ref e) => write!(f, "i/o error: {}", e),
+            BinaryError::Encoding(ref e) => write!(f, "encoding error: {}", e),
+            BinaryError::BadMagic(magic) => write!(f, "bad magic number {:#010x}", magic),
+            BinaryError::UnsupportedVersion(v) => write!(f, "unsupported version {}", v),
+            BinaryError::TooLarge => write!(f, "graph too large"),
+            BinaryError::InvalidEdge(a, b) => {
+                write!(f, "edge ({}, {}) has an endpoint out of bounds", a, b)
+            }
+        }
+    }
+}
+
+impl Error for BinaryError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            BinaryError::Io(ref e) => Some(e),
+            BinaryError::Encoding(ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for BinaryError {
+    fn from(e: io::Error) -> Self {
+        BinaryError::Io(e)
+    }
+}
+
+impl From<bincode::Error> for BinaryError {
+    fn from(e: bincode::Error) -> Self {
+        BinaryError::Encoding(e)
+    }
+}
+
+impl<N, E, Ty, Ix> Graph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Write the graph in the binary format described in the
+    /// [module documentation](index.html).
+    ///
+    /// Return `BinaryError::TooLarge` if there are more than `u32::MAX` nodes
+    /// or edges.
+    pub fn write_binary<W: Write>(&self, mut writer: W) -> Result<(), BinaryError>
+    where
+        N: Serialize,
+        E: Serialize,
+    {
+        writer.write_all(&MAGIC.to_be_bytes())?;
+        writer.write_all(&[VERSION])?;
+        write_u32(&mut writer, self.node_count())?;
+        for node in self.raw_nodes() {
+            bincode::serialize_into(&mut writer, &node.data)?;
+        }
+        write_u32(&mut writer, self.edge_count())?;
+        for edge in self.raw_edges() {
+            write_u32(&mut writer, edge.source().index())?;
+            write_u32(&mut writer, edge.target().index())?;
+            bincode::serialize_into(&mut writer, &edge.weight)?;
+        }
+        Ok(())
+    }
+
+    /// Read a graph written by [`write_binary`](#method.write_binary).
+    ///
+    /// Node and edge indices are the same as in the written graph.
+    pub fn read_binary<R: Read>(mut reader: R) -> Result<Self, BinaryError>
+    where
+        N: DeserializeOwned,
+        E: DeserializeOwned,
+    {
+        let mut header = [0; 5];
+        reader.read_exact(&mut header)?;
+        let magic = u32::from_be_bytes([header[0], header[1], header[2], header[3]]);
+        if magic != MAGIC {
+            return Err(BinaryError::BadMagic(magic));
+        }
+        if header[4] != VERSION {
+            return Err(BinaryError::UnsupportedVersion(header[4]));
+        }
+
+        let node_count = read_count::<_, Ix>(&mut reader)?;
+        // don't trust the counts for more than a small preallocation
+        let mut g = Graph::with_capacity(node_count.min(1024), 0);
+        for _ in 0..node_count {
+            g.add_node(bincode::deserialize_from(&mut reader)?);
+        }
+        let edge_count = read_count::<_, Ix>(&mut reader)?;
+        g.reserve_edges(edge_count.min(1024));
+        for _ in 0..edge_count {
+            let a = read_u32(&mut reader)?;
+            let b = read_u32(&mut reader)?;
+            if a as usize >= node_count || b as usize >= node_count {
+                return Err(BinaryError::InvalidEdge(a, b));
+            }
+            let weight = bincode::deserialize_from(&mut reader)?;
+            g.add_edge(
+                NodeIndex::new(a as usize),
+                NodeIndex::new(b as usize),
+                weight,
+            );
+        }
+        Ok(g)
+    }
+}
+
+fn write_u32<W: Write>(writer: &mut W, x: usize) -> Result<(), BinaryError> {
+    let x = u32::try_from(x).map_err(|_| BinaryError::TooLarge)?;
+    writer.write_all(&x.to_le_bytes())?;
+    Ok(())
+}
+
+fn read_u32<R: Read>(reader: &mut R) -> Result<u32, BinaryError> {
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+/// Read a node or edge count and check that it fits into `Ix`.
+fn read_count<R: Read, Ix: IndexType>(reader: &mut R) -> Result<usize, BinaryError> {
+    let count = read_u32(reader)? as usize;
+    if count > <Ix as IndexType>::max().index() {
+        return Err(BinaryError::TooLarge);
+    }
+    Ok(count)
+}
@@ -0,0 +1,564 @@
+//! Simple graphviz dot file format output and input.
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Display, Write};
+
+use graph::{DiGraph, EdgeIndex, EdgeType, Graph, IndexType, NodeIndex, UnGraph};
+
+/// `Dot` implements output to graphviz .dot format for a graph.
+///
+/// Formatting and options are rather simple, this is mostly intended
+/// for debugging. Exact output may change.
+///
+/// ```
+/// use graphs::*;
+/// use graphs::dot::{Config, Dot};
+///
+/// let mut g = Graph::<&str, u32>::new();
+/// let a = g.add_node("a");
+/// let b = g.add_node("b");
+/// g.add_edge(a, b, 7);
+///
+/// assert_eq!(
+///     format!("{}", Dot::new(&g)),
+///     "digraph {\n    0 [label=\"a\"]\n    1 [label=\"b\"]\n    0 -> 1 [label=\"7\"]\n}\n"
+/// );
+/// assert_eq!(
+///     format!("{}", Dot::with_config(&g, &[Config::EdgeNoLabel])),
+///     "digraph {\n    0 [label=\"a\"]\n    1 [label=\"b\"]\n    0 -> 1\n}\n"
+/// );
+/// ```
+///
+/// Pipe the output to `dot -Tsvg` to get a picture of the graph.
+pub struct Dot<'a, N: 'a, E: 'a, Ty: 'a, Ix: 'a> {
+    graph: &'a Graph<N, E, Ty, Ix>,
+    config: &'a [Config],
+}
+
+/// `Dot` configuration.
+///
+/// This enum does not have an exhaustive definition (will be expanded)
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Config {
+    /// Use indices for node labels.
+    NodeIndexLabel,
+    /// Use indices for edge labels.
+    EdgeIndexLabel,
+    /// Use no edge labels.
+    EdgeNoLabel,
+    #[doc(hidden)]
+    _Incomplete(()),
+}
+
+impl<'a, N, E, Ty, Ix> Dot<'a, N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Create a `Dot` formatting wrapper with default configuration.
+    pub fn new(graph: &'a Graph<N, E, Ty, Ix>) -> Self {
+        Self::with_config(graph, &[])
+    }
+    /// Create a `Dot` formatting wrapper with custom configuration.
+    pub fn with_config(graph: &'a Graph<N, E, Ty, Ix>, config: &'a [Config]) -> Self {
+        Dot { graph, config }
+    }
+}
+
+impl<'a, N, E, Ty, Ix> Display for Dot<'a, N, E, Ty, Ix>
+where
+    N: Display,
+    E: Display,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let index_labels = self.config.contains(&Config::NodeIndexLabel);
+        let node_label = |w: &mut dyn Write, i: NodeIndex<Ix>, data: &N| {
+            if index_labels {
+                write!(w, "{}", i.index())
+            } else {
+                write!(w, "{}", data)
+            }
+        };
+        let index_labels = self.config.contains(&Config::EdgeIndexLabel);
+        let edge_label = |w: &mut dyn Write, i: EdgeIndex<Ix>, weight: &E| {
+            if index_labels {
+                write!(w, "{}", i.index())
+            } else {
+                write!(w, "{}", weight)
+            }
+        };
+        let edge_label = if self.config.contains(&Config::EdgeNoLabel) {
+            None
+        } else {
+            Some(edge_label)
+        };
+        write_dot(f, self.graph, Dialect::Plain, node_label, edge_label)
+    }
+}
+
+/// The header and statement style of the dot output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Dialect {
+    /// `digraph { ... }`, written by `Dot`.
+    Plain,
+    /// `digraph G { ... }` with a `;` after every statement, written by
+    /// `Graph::to_dot`.
+    Named,
+}
+
+/// Write `graph` in dot format, naming the nodes by their index.
+///
+/// `node_label` and `edge_label` write the labels, which are escaped on
+/// the way. Edges get no label if `edge_label` is `None`.
+pub(crate) fn write_dot<W, N, E, Ty, Ix, FN, FE>(
+    out: &mut W,
+    graph: &Graph<N, E, Ty, Ix>,
+    dialect: Dialect,
+    mut node_label: FN,
+    mut edge_label: Option<FE>,
+) -> fmt::Result
+where
+    W: Write,
+    Ty: EdgeType,
+    Ix: IndexType,
+    FN: FnMut(&mut dyn Write, NodeIndex<Ix>, &N) -> fmt::Result,
+    FE: FnMut(&mut dyn Write, EdgeIndex<Ix>, &E) -> fmt::Result,
+{
+    let (kind, edge_op) = if graph.is_directed() {
+        ("digraph", "->")
+    } else {
+        ("graph", "--")
+    };
+    let (name, end) = match dialect {
+        Dialect::Plain => ("", ""),
+        Dialect::Named => (" G", ";"),
+    };
+    writeln!(out, "{}{} {{", kind, name)?;
+    for (i, node) in graph.raw_nodes().iter().enumerate() {
+        write!(out, "    {} [label=\"", i)?;
+        node_label(&mut Escaper(&mut *out), NodeIndex::new(i), &node.data)?;
+        writeln!(out, "\"]{}", end)?;
+    }
+    for (i, edge) in graph.raw_edges().iter().enumerate() {
+        write!(
+            out,
+            "    {} {} {}",
+            edge.source().index(),
+            edge_op,
+            edge.target().index()
+        )?;
+        if let Some(ref mut edge_label) = edge_label {
+            write!(out, " [label=\"")?;
+            edge_label(&mut Escaper(&mut *out), EdgeIndex::new(i), &edge.weight)?;
+            write!(out, "\"]")?;
+        }
+        writeln!(out, "{}", end)?;
+    }
+    writeln!(out, "}}")
+}
+
+/// Escape a string for use inside a quoted dot identifier.
+pub fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    Escaper(&mut escaped).write_str(s).unwrap();
+    escaped
+}
+
+/// Writer adaptor that escapes quotes, backslashes and newlines.
+struct Escaper<W>(W);
+
+impl<W: Write> Write for Escaper<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            self.write_char(c)?;
+        }
+        Ok(())
+    }
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        match c {
+            '"' | '\\' => {
+                self.0.write_char('\\')?;
+                self.0.write_char(c)
+            }
+            '\n' => self.0.write_str("\\n"),
+            c => self.0.write_char(c),
+        }
+    }
+}
+
+/// Error returned by [`from_dot`](fn.from_dot.html) and
+/// [`from_dot_undirected`](fn.from_dot_undirected.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DotParseError {
+    /// Line of the offending input, starting at 1.
+    pub line: usize,
+    /// Column of the offending input, starting at 1.
+    pub column: usize,
+    /// What went wrong.
+    pub message: String,
+}
+
+impl Display for DotParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl Error for DotParseError {}
+
+/// Parse a `digraph` in dot format.
+///
+/// Supports the subset of the format that [`Dot`](struct.Dot.html) and
+/// `Graph::to_dot` emit: node statements, chains of edges like
+/// `a -> b -> c`, attribute lists, quoted identifiers and comments.
+/// Graph attributes and `graph`/`node`/`edge` default attribute statements
+/// are skipped, subgraphs are not supported.
+///
+/// Every node's data is its `label` attribute, or its name if it has none.
+/// Every edge's weight is its `label` attribute, or the empty string.
+/// Nodes are numbered in order of their first appearance.
+///
+/// ```
+/// use graphs::dot::from_dot;
+///
+/// let g = from_dot("digraph { a -> b [label=\"7\"]; b [label=\"B\"] }").unwrap();
+/// assert_eq!(g.node_count(), 2);
+/// assert_eq!(g.raw_nodes()[1].data, "B");
+/// assert_eq!(g.raw_edges()[0].weight, "7");
+/// ```
+pub fn from_dot(input: &str) -> Result<DiGraph<String, String>, DotParseError> {
+    Parser::new(input)?.parse()
+}
+
+/// Parse an undirected `graph` in dot format.
+///
+/// See [`from_dot`](fn.from_dot.html) for the supported subset.
+pub fn from_dot_undirected(input: &str) -> Result<UnGraph<String, String>, DotParseError> {
+    Parser::new(input)?.parse()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Id(String),
+    LBrace,
+    RBrace,
+    LBracket,
+    RBracket,
+    Equals,
+    Semicolon,
+    Comma,
+    EdgeOp(bool),
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize, usize)>,
+    pos: usize,
+    end: (usize, usize),
+}
+
+impl Parser {
+    fn new(input: &str) -> Result<Parser, DotParseError> {
+        let (tokens, end) = tokenize(input)?;
+        Ok(Parser {
+            tokens,
+            pos: 0,
+            end,
+        })
+    }
+
+    fn parse<Ty: EdgeType>(&mut self) -> Result<Graph<String, String, Ty>, DotParseError> {
+        if self.peek_keyword("strict") {
+            self.pos += 1;
+        }
+        let kind = if Ty::is_directed() {
+            "digraph"
+        } else {
+            "graph"
+        };
+        if !self.peek_keyword(kind) {
+            return Err(self.error(format!("expected `{}`", kind)));
+        }
+        self.pos += 1;
+        if let Some(&(Token::Id(_), _, _)) = self.tokens.get(self.pos) {
+            self.pos += 1;
+        }
+        self.expect(&Token::LBrace, "`{`")?;
+
+        let mut g = Graph::with_capacity(0, 0);
+        let mut names = HashMap::new();
+        loop {
+            let name = match self.next() {
+                Some(Token::RBrace) => break,
+                Some(Token::Semicolon) => continue,
+                Some(Token::Id(name)) => name,
+                Some(_) => {
+                    self.pos -= 1;
+                    return Err(self.error("expected a statement".to_string()));
+                }
+                None => return Err(self.error("expected `}`".to_string())),
+            };
+            if ["graph", "node", "edge"].contains(&&*name.to_lowercase())
+                && self.peek() == Some(&Token::LBracket)
+            {
+                self.attributes()?;
+                continue;
+            }
+            if self.peek() == Some(&Token::Equals) {
+                self.pos += 1;
+                self.id()?;
+                continue;
+            }
+            if self.peek() == Some(&Token::LBrace) || name.to_lowercase() == "subgraph" {
+                return Err(self.error("subgraphs are not supported".to_string()));
+            }
+
+            let mut chain = vec![node(&mut g, &mut names, name)];
+            while let Some(&Token::EdgeOp(directed)) = self.peek() {
+                if directed != Ty::is_directed() {
+                    let op = if Ty::is_directed() { "->" } else { "--" };
+                    return Err(self.error(format!("expected edge operator `{}`", op)));
+                }
+                self.pos += 1;
+                let name = self.id()?;
+                chain.push(node(&mut g, &mut names, name));
+            }
+            let label = self.attributes()?;
+            if chain.len() == 1 {
+                if let Some(label) = label {
+                    g[chain[0]] = label;
+                }
+            } else {
+                for pair in chain.windows(2) {
+                    g.add_edge(pair[0], pair[1], label.clone().unwrap_or_default());
+                }
+            }
+        }
+        if self.pos < self.tokens.len() {
+            return Err(self.error("unexpected input after `}`".to_string()));
+        }
+        Ok(g)
+    }
+
+    /// Parse any number of attribute lists, returning the last `label`.
+    fn attributes(&mut self) -> Result<Option<String>, DotParseError> {
+        let mut label = None;
+        while self.peek() == Some(&Token::LBracket) {
+            self.pos += 1;
+            loop {
+                match self.peek() {
+                    Some(&Token::RBracket) => {
+                        self.pos += 1;
+                        break;
+                    }
+                    Some(&Token::Comma) | Some(&Token::Semicolon) => {
+                        self.pos += 1;
+                        continue;
+                    }
+                    _ => {}
+                }
+                let key = self.id()?;
+                self.expect(&Token::Equals, "`=`")?;
+                let value = self.id()?;
+                if key == "label" {
+                    label = Some(value);
+                }
+            }
+        }
+        Ok(label)
+    }
+
+    fn id(&mut self) -> Result<String, DotParseError> {
+        match self.tokens.get(self.pos) {
+            Some(&(Token::Id(ref id), _, _)) => {
+                self.pos += 1;
+                Ok(id.clone())
+            }
+            _ => Err(self.error("expected an identifier".to_string())),
+        }
+    }
+
+    fn expect(&mut self, token: &Token, what: &str) -> Result<(), DotParseError> {
+        if self.peek() == Some(token) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error(format!("expected {}", what)))
+        }
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|t| &t.0)
+    }
+
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        match self.peek() {
+            Some(Token::Id(id)) => id.to_lowercase() == keyword,
+            _ => false,
+        }
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.peek().cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn error(&self, message: String) -> DotParseError {
+        let (line, column) = match self.tokens.get(self.pos) {
+            Some(&(_, line, column)) => (line, column),
+            None => self.end,
+        };
+        DotParseError {
+            line,
+            column,
+            message,
+        }
+    }
+}
+
+/// Return the index of the node called `name`, adding it if it is new.
+fn node<Ty: EdgeType>(
+    g: &mut Graph<String, String, Ty>,
+    names: &mut HashMap<String, NodeIndex>,
+    name: String,
+) -> NodeIndex {
+    if let Some(&ix) = names.get(&name) {
+        return ix;
+    }
+    let ix = g.add_node(name.clone());
+    names.insert(name, ix);
+    ix
+}
+
+type Tokens = (Vec<(Token, usize, usize)>, (usize, usize));
+
+fn tokenize(input: &str) -> Result<Tokens, DotParseError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+    let (mut line, mut column) = (1, 1);
+    macro_rules! bump {
+        () => {{
+            let c = chars.next();
+            if c == Some('\n') {
+                line += 1;
+                column = 1;
+            } else {
+                column += 1;
+            }
+            c
+        }};
+    }
+    let error = |line, column, message: &str| DotParseError {
+        line,
+        column,
+        message: message.to_string(),
+    };
+    while let Some(&c) = chars.peek() {
+        let (start_line, start_column) = (line, column);
+        let token = match c {
+            c if c.is_whitespace() => {
+                bump!();
+                continue;
+            }
+            '#' => {
+                while chars.peek().is_some_and(|&c| c != '\n') {
+                    bump!();
+                }
+                continue;
+            }
+            '/' => {
+                bump!();
+                match bump!() {
+                    Some('/') => {
+                        while chars.peek().is_some_and(|&c| c != '\n') {
+                            bump!();
+                        }
+                    }
+                    Some('*') => loop {
+                        match bump!() {
+                            Some('*') if chars.peek() == Some(&'/') => {
+                                bump!();
+                                break;
+                            }
+                            Some(_) => {}
+                            None => {
+                                return Err(error(start_line, start_column, "unterminated comment"))
+                            }
+                        }
+                    },
+                    _ => return Err(error(start_line, start_column, "unexpected `/`")),
+                }
+                continue;
+            }
+            '{' | '}' | '[' | ']' | '=' | ';' | ',' => {
+                bump!();
+                match c {
+                    '{' => Token::LBrace,
+                    '}' => Token::RBrace,
+                    '[' => Token::LBracket,
+                    ']' => Token::RBracket,
+                    '=' => Token::Equals,
+                    ';' => Token::Semicolon,
+                    _ => Token::Comma,
+                }
+            }
+            '"' => {
+                bump!();
+                let mut id = String::new();
+                loop {
+                    match bump!() {
+                        Some('"') => break,
+                        Some('\\') => match bump!() {
+                            Some('n') => id.push('\n'),
+                            Some('"') => id.push('"'),
+                            Some('\\') => id.push('\\'),
+                            Some('\n') => {}
+                            Some(c) => {
+                                id.push('\\');
+                                id.push(c);
+                            }
+                            None => {
+                                return Err(error(start_line, start_column, "unterminated string"))
+                            }
+                        },
+                        Some(c) => id.push(c),
+                        None => return Err(error(start_line, start_column, "unterminated string")),
+                    }
+                }
+                Token::Id(id)
+            }
+            '-' if {
+                let mut ahead = chars.clone();
+                ahead.next();
+                ahead.peek() == Some(&'>') || ahead.peek() == Some(&'-')
+            } =>
+            {
+                bump!();
+                Token::EdgeOp(bump!() == Some('>'))
+            }
+            c if c.is_alphanumeric() || c == '_' || c == '.' || c == '-' => {
+                let mut id = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c.is_alphanumeric() || c == '_' || c == '.' || (c == '-' && id.is_empty()) {
+                        id.push(c);
+                        bump!();
+                    } else {
+                        break;
+                    }
+                }
+                Token::Id(id)
+            }
+            c => {
+                return Err(error(
+                    start_line,
+                    start_column,
+                    &format!("unexpected character `{}`", c),
+                ))
+            }
+        };
+        tokens.push((token, start_line, start_column));
+    }
+    Ok((tokens, (line, column)))
+}
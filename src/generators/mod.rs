@@ -0,0 +1,222 @@
+//! Generators for classic families of graphs.
+//!
+//! Every generator creates its nodes and edges with `Default::default()` as
+//! data and weight, and works for both directed and undirected graphs. The
+//! layout of the node indices is documented for each of them; directed edges
+//! point from lower to higher indices unless stated otherwise.
+//!
+//! Random graphs are generated by the [`random`](random/index.html) module,
+//! small well-known graphs by the [`named`](named/index.html) module.
+use graph::{EdgeType, Graph, IndexType, NodeIndex};
+
+pub mod named;
+pub mod random;
+
+/// Create a graph with `n` nodes and no edges, reserving room for `edges`.
+fn empty_graph<N, E, Ty, Ix>(n: usize, edges: usize) -> Graph<N, E, Ty, Ix>
+where
+    N: Default,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    let mut g = Graph::with_capacity(n, edges);
+    for _ in 0..n {
+        g.add_node(N::default());
+    }
+    g
+}
+
+/// Add an edge between the nodes with indices `a` and `b`.
+fn connect<N, E, Ty, Ix>(g: &mut Graph<N, E, Ty, Ix>, a: usize, b: usize)
+where
+    E: Default,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    g.add_edge(NodeIndex::new(a), NodeIndex::new(b), E::default());
+}
+
+/// Return the complete graph on `n` nodes.
+///
+/// Undirected graphs get an edge between every pair of distinct nodes,
+/// directed graphs an edge in each direction.
+pub fn complete_graph<N, E, Ty, Ix>(n: usize) -> Graph<N, E, Ty, Ix>
+where
+    N: Default,
+    E: Default,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    let pairs = n * n.saturating_sub(1);
+    let mut g = empty_graph(n, if Ty::is_directed() { pairs } else { pairs / 2 });
+    for a in 0..n {
+        for b in 0..n {
+            if a < b || (a > b && Ty::is_directed()) {
+                connect(&mut g, a, b);
+            }
+        }
+    }
+    g
+}
+
+/// Return the path `0 - 1 - ... - (n - 1)`.
+pub fn path_graph<N, E, Ty, Ix>(n: usize) -> Graph<N, E, Ty, Ix>
+where
+    N: Default,
+    E: Default,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    let mut g = empty_graph(n, n.saturating_sub(1));
+    for a in 1..n {
+        connect(&mut g, a - 1, a);
+    }
+    g
+}
+
+/// Return the cycle `0 - 1 - ... - (n - 1) - 0`.
+///
+/// The closing edge from `n - 1` to `0` is only added for `n >= 3`, so
+/// smaller cycles are paths without self-loops or parallel edges.
+pub fn cycle_graph<N, E, Ty, Ix>(n: usize) -> Graph<N, E, Ty, Ix>
+where
+    N: Default,
+    E: Default,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    let mut g = path_graph(n);
+    if n >= 3 {
+        connect(&mut g, n - 1, 0);
+    }
+    g
+}
+
+/// Return the star with center `0` and the `n` leaves `1..=n`.
+pub fn star_graph<N, E, Ty, Ix>(n: usize) -> Graph<N, E, Ty, Ix>
+where
+    N: Default,
+    E: Default,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    let mut g = empty_graph(n + 1, n);
+    for leaf in 1..=n {
+        connect(&mut g, 0, leaf);
+    }
+    g
+}
+
+/// Return the `rows × cols` grid.
+///
+/// The nodes are laid out row-major: the node in row `r` and column `c` has
+/// index `r * cols + c`. Every node is connected to its right and lower
+/// neighbor.
+pub fn grid_graph<N, E, Ty, Ix>(rows: usize, cols: usize) -> Graph<N, E, Ty, Ix>
+where
+    N: Default,
+    E: Default,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    let edges = rows * cols.saturating_sub(1) + rows.saturating_sub(1) * cols;
+    let mut g = empty_graph(rows * cols, edges);
+    for r in 0..rows {
+        for c in 0..cols {
+            let a = r * cols + c;
+            if c + 1 < cols {
+                connect(&mut g, a, a + 1);
+            }
+            if r + 1 < rows {
+                connect(&mut g, a, a + cols);
+            }
+        }
+    }
+    g
+}
+
+/// Return the `rows × cols` grid where every node is also connected to its
+/// diagonal neighbors, the king's moves on a chessboard.
+///
+/// The nodes are laid out row-major like in [`grid_graph`](fn.grid_graph.html).
+/// Every node is connected to its right, lower, lower right and lower left
+/// neighbor.
+pub fn grid_graph_8connected<N, E, Ty, Ix>(rows: usize, cols: usize) -> Graph<N, E, Ty, Ix>
+where
+    N: Default,
+    E: Default,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    let diagonals = 2 * rows.saturating_sub(1) * cols.saturating_sub(1);
+    let edges = rows * cols.saturating_sub(1) + rows.saturating_sub(1) * cols + diagonals;
+    let mut g = empty_graph(rows * cols, edges);
+    for r in 0..rows {
+        for c in 0..cols {
+            let a = r * cols + c;
+            if c + 1 < cols {
+                connect(&mut g, a, a + 1);
+            }
+            if r + 1 < rows {
+                connect(&mut g, a, a + cols);
+                if c + 1 < cols {
+                    connect(&mut g, a, a + cols + 1);
+                }
+                if c > 0 {
+                    connect(&mut g, a, a + cols - 1);
+                }
+            }
+        }
+    }
+    g
+}
+
+/// Return the full binary tree of the given `depth`, with
+/// `2^(depth + 1) - 1` nodes.
+///
+/// The nodes are numbered level by level: the root is `0` and the children
+/// of node `i` are `2 * i + 1` and `2 * i + 2`. Edges point from parent to
+/// child.
+///
+/// **Panics** if the node count doesn't fit in a `usize`, i.e. if `depth` is
+/// at least `usize::BITS - 1`.
+pub fn binary_tree<N, E, Ty, Ix>(depth: u32) -> Graph<N, E, Ty, Ix>
+where
+    N: Default,
+    E: Default,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    assert!(
+        depth < usize::BITS - 1,
+        "binary_tree: depth {} too large",
+        depth
+    );
+    let n = (1usize << (depth + 1)) - 1;
+    let mut g = empty_graph(n, n - 1);
+    for child in 1..n {
+        connect(&mut g, (child - 1) / 2, child);
+    }
+    g
+}
+
+/// Return the complete bipartite graph `K(m, n)`.
+///
+/// The first part are the nodes `0..m`, the second part the nodes
+/// `m..m + n`. Every node of the first part is connected to every node of the
+/// second part, directed edges point from the first part to the second.
+pub fn complete_bipartite<N, E, Ty, Ix>(m: usize, n: usize) -> Graph<N, E, Ty, Ix>
+where
+    N: Default,
+    E: Default,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    let mut g = empty_graph(m + n, m * n);
+    for a in 0..m {
+        for b in m..m + n {
+            connect(&mut g, a, b);
+        }
+    }
+    g
+}
@@ -0,0 +1,153 @@
+//! Small named graphs, mostly useful as test inputs for algorithms.
+//!
+//! Like the other generators, these work for both directed and undirected
+//! graphs; directed edges point from lower to higher indices.
+use super::{complete_bipartite, complete_graph, connect, empty_graph};
+use graph::{EdgeType, Graph, IndexType};
+
+/// Create a graph with `n` nodes and an edge for every pair in `edges`.
+fn from_pairs<N, E, Ty, Ix>(n: usize, edges: &[(usize, usize)]) -> Graph<N, E, Ty, Ix>
+where
+    N: Default,
+    E: Default,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    let mut g = empty_graph(n, edges.len());
+    for &(a, b) in edges {
+        connect(&mut g, a, b);
+    }
+    g
+}
+
+/// Create a cubic graph from its LCF notation: the cycle `0 - 1 - ... -
+/// (n - 1) - 0` plus a chord from every node `i` to `i + shifts[i % len]`.
+fn from_lcf<N, E, Ty, Ix>(n: usize, shifts: &[isize]) -> Graph<N, E, Ty, Ix>
+where
+    N: Default,
+    E: Default,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    let mut edges: Vec<_> = (0..n).map(|i| (i, (i + 1) % n)).collect();
+    for i in 0..n {
+        let j = (i as isize + shifts[i % shifts.len()]).rem_euclid(n as isize) as usize;
+        // every chord is listed from both of its ends
+        if i < j {
+            edges.push((i, j));
+        }
+    }
+    for edge in &mut edges {
+        if edge.0 > edge.1 {
+            *edge = (edge.1, edge.0);
+        }
+    }
+    from_pairs(n, &edges)
+}
+
+/// Return the Petersen graph: 10 nodes, 15 edges, 3-regular with girth 5
+/// and not Hamiltonian.
+///
+/// The outer cycle is `0 - 1 - 2 - 3 - 4 - 0`, node `i` is connected to the
+/// inner node `i + 5`, and the inner nodes form the pentagram
+/// `5 - 7 - 9 - 6 - 8 - 5`.
+pub fn petersen<N, E, Ty, Ix>() -> Graph<N, E, Ty, Ix>
+where
+    N: Default,
+    E: Default,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    let mut edges = Vec::with_capacity(15);
+    for i in 0..5 {
+        edges.push((i, i + 5));
+        edges.push(if i < 4 { (i, i + 1) } else { (0, 4) });
+        let (a, b) = (5 + i, 5 + (i + 2) % 5);
+        edges.push(if a < b { (a, b) } else { (b, a) });
+    }
+    from_pairs(10, &edges)
+}
+
+/// Return the complete graph `K5`, one of Kuratowski's two minimal
+/// non-planar graphs.
+pub fn k5<N, E, Ty, Ix>() -> Graph<N, E, Ty, Ix>
+where
+    N: Default,
+    E: Default,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    complete_graph(5)
+}
+
+/// Return the complete bipartite graph `K(3, 3)`, one of Kuratowski's two
+/// minimal non-planar graphs, with the parts `0..3` and `3..6`.
+pub fn complete_bipartite_k33<N, E, Ty, Ix>() -> Graph<N, E, Ty, Ix>
+where
+    N: Default,
+    E: Default,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    complete_bipartite(3, 3)
+}
+
+/// Return the graph of the dodecahedron: 20 nodes, 30 edges, 3-regular
+/// with girth 5 and diameter 5.
+///
+/// The nodes `0..20` form a Hamiltonian cycle.
+pub fn dodecahedron<N, E, Ty, Ix>() -> Graph<N, E, Ty, Ix>
+where
+    N: Default,
+    E: Default,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    from_lcf(20, &[10, 7, 4, -4, -7, 10, -4, 7, -7, 4])
+}
+
+/// Return the graph of the icosahedron: 12 nodes, 30 edges, 5-regular
+/// with diameter 3.
+pub fn icosahedron<N, E, Ty, Ix>() -> Graph<N, E, Ty, Ix>
+where
+    N: Default,
+    E: Default,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    from_pairs(
+        12,
+        &[
+            (0, 1),
+            (0, 5),
+            (0, 7),
+            (0, 8),
+            (0, 11),
+            (1, 2),
+            (1, 5),
+            (1, 6),
+            (1, 8),
+            (2, 3),
+            (2, 6),
+            (2, 8),
+            (2, 9),
+            (3, 4),
+            (3, 6),
+            (3, 9),
+            (3, 10),
+            (4, 5),
+            (4, 6),
+            (4, 10),
+            (4, 11),
+            (5, 6),
+            (5, 11),
+            (7, 8),
+            (7, 9),
+            (7, 10),
+            (7, 11),
+            (8, 9),
+            (9, 10),
+            (10, 11),
+        ],
+    )
+}
@@ -0,0 +1,264 @@
+//! Generators for random graphs.
+//!
+//! All generators take a `seed` and are deterministic: the same arguments
+//! produce the same graph, with the same node and edge order, on every
+//! platform. The sequence may change between releases of this crate.
+//! The generated graphs are simple: they have no self-loops and no parallel
+//! edges.
+use std::collections::HashSet;
+
+use graph::{EdgeType, Graph, IndexType, NodeIndex};
+
+/// SplitMix64, small and good enough for generating graphs.
+struct Rng(u64);
+
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `[0, 1)`.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Uniform in `0..n`, for `n > 0`.
+    fn below(&mut self, n: usize) -> usize {
+        ((u128::from(self.next_u64()) * n as u128) >> 64) as usize
+    }
+}
+
+fn empty_graph<Ty: EdgeType, Ix: IndexType>(n: usize, edges: usize) -> Graph<(), (), Ty, Ix> {
+    let mut g = Graph::with_capacity(n, edges);
+    for _ in 0..n {
+        g.add_node(());
+    }
+    g
+}
+
+/// Number of possible edges between `n` nodes without self-loops.
+fn pair_count<Ty: EdgeType>(n: usize) -> usize {
+    let ordered = n * n.saturating_sub(1);
+    if Ty::is_directed() {
+        ordered
+    } else {
+        ordered / 2
+    }
+}
+
+/// Add the edges with the given sorted pair indices, see `pair_count`.
+///
+/// Undirected pairs `(i, j)` with `i < j` are numbered by `j` first, then
+/// `i`; directed pairs `(a, b)` with `a != b` by `a` first, then `b`.
+fn add_pairs<Ty, Ix, I>(g: &mut Graph<(), (), Ty, Ix>, indices: I)
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+    I: IntoIterator<Item = usize>,
+{
+    let n = g.node_count();
+    // start of the current column of undirected pairs
+    let (mut j, mut start) = (1, 0);
+    for index in indices {
+        let (a, b) = if Ty::is_directed() {
+            let (a, b) = (index / (n - 1), index % (n - 1));
+            (a, if b >= a { b + 1 } else { b })
+        } else {
+            while index >= start + j {
+                start += j;
+                j += 1;
+            }
+            (index - start, j)
+        };
+        g.add_edge(NodeIndex::new(a), NodeIndex::new(b), ());
+    }
+}
+
+/// Return an Erdős–Rényi random graph `G(n, p)`: every possible edge
+/// exists independently with probability `p`.
+///
+/// Uses geometric skipping between the edges, so it runs in
+/// **O(n + m)** time for `m` generated edges.
+///
+/// **Panics** if `p` is not in `[0, 1]`.
+pub fn gnp_random_graph<Ty, Ix>(n: usize, p: f64, seed: u64) -> Graph<(), (), Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    assert!(
+        (0.0..=1.0).contains(&p),
+        "gnp_random_graph: p must be in [0, 1]"
+    );
+    let total = pair_count::<Ty>(n);
+    let mut g = empty_graph(n, (total as f64 * p) as usize);
+    if p == 0.0 {
+        return g;
+    }
+    if p == 1.0 {
+        add_pairs(&mut g, 0..total);
+        return g;
+    }
+    let mut rng = Rng(seed);
+    let log_q = (1.0 - p).ln();
+    let mut indices = Vec::new();
+    let mut index = 0usize;
+    loop {
+        // number of pairs skipped before the next edge
+        let skip = ((1.0 - rng.next_f64()).ln() / log_q).floor();
+        if skip >= (total - index) as f64 {
+            break;
+        }
+        index += skip as usize;
+        indices.push(index);
+        index += 1;
+    }
+    add_pairs(&mut g, indices);
+    g
+}
+
+/// Return a random graph `G(n, m)`, chosen uniformly among all graphs with
+/// `n` nodes and `m` edges.
+///
+/// Directed edges `(a, b)` are added sorted by `a`, then `b`; undirected
+/// edges `(i, j)` with `i < j` sorted by `j`, then `i`.
+///
+/// **Panics** if `m` is larger than the number of possible edges.
+pub fn gnm_random_graph<Ty, Ix>(n: usize, m: usize, seed: u64) -> Graph<(), (), Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    let total = pair_count::<Ty>(n);
+    assert!(m <= total, "gnm_random_graph: too many edges");
+    let mut rng = Rng(seed);
+    // pick whichever of the edges and the non-edges is the smaller set
+    let picks = m.min(total - m);
+    let mut picked = HashSet::with_capacity(picks);
+    while picked.len() < picks {
+        picked.insert(rng.below(total));
+    }
+    let mut g = empty_graph(n, m);
+    if picks == m {
+        let mut indices: Vec<_> = picked.into_iter().collect();
+        indices.sort_unstable();
+        add_pairs(&mut g, indices);
+    } else {
+        add_pairs(&mut g, (0..total).filter(|i| !picked.contains(i)));
+    }
+    g
+}
+
+/// Return a Barabási–Albert preferential attachment graph.
+///
+/// Starts from a star with center `0` and the `m` leaves `1..=m`. Every
+/// further node is connected to `m` distinct existing nodes, picked with
+/// probability proportional to their degree. The result has `n` nodes and
+/// `m * (n - m)` edges; directed edges point from the newer to the older
+/// node, except for the initial star.
+///
+/// **Panics** unless `1 <= m < n`.
+pub fn barabasi_albert<Ty, Ix>(n: usize, m: usize, seed: u64) -> Graph<(), (), Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    assert!(m >= 1 && m < n, "barabasi_albert: need 1 <= m < n");
+    let mut rng = Rng(seed);
+    let mut g = empty_graph(n, m * (n - m));
+    // every node appears once per incident edge
+    let mut repeated = Vec::with_capacity(2 * m * (n - m));
+    for leaf in 1..=m {
+        g.add_edge(NodeIndex::new(0), NodeIndex::new(leaf), ());
+        repeated.push(0);
+        repeated.push(leaf);
+    }
+    let mut targets = Vec::with_capacity(m);
+    for source in m + 1..n {
+        targets.clear();
+        while targets.len() < m {
+            let target = repeated[rng.below(repeated.len())];
+            if !targets.contains(&target) {
+                targets.push(target);
+            }
+        }
+        for &target in &targets {
+            g.add_edge(NodeIndex::new(source), NodeIndex::new(target), ());
+            repeated.push(source);
+            repeated.push(target);
+        }
+    }
+    g
+}
+
+/// Return a Watts–Strogatz small-world graph.
+///
+/// Starts from a ring of `n` nodes, each connected to its `k / 2` nearest
+/// successors (edge `(u, (u + j) % n)` for `j` in `1..=k / 2`). Then every
+/// edge is rewired with probability `beta`: its second endpoint is replaced
+/// by a uniformly random node, avoiding self-loops and parallel edges. The
+/// edge count `n * (k / 2)` doesn't change.
+///
+/// **Panics** unless `k < n` and `beta` is in `[0, 1]`.
+pub fn watts_strogatz<Ty, Ix>(n: usize, k: usize, beta: f64, seed: u64) -> Graph<(), (), Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    assert!(k < n, "watts_strogatz: need k < n");
+    assert!(
+        (0.0..=1.0).contains(&beta),
+        "watts_strogatz: beta must be in [0, 1]"
+    );
+    let key = |a: usize, b: usize| {
+        if Ty::is_directed() || a < b {
+            (a, b)
+        } else {
+            (b, a)
+        }
+    };
+    let mut rng = Rng(seed);
+    let mut edges = Vec::with_capacity(n * (k / 2));
+    for j in 1..=k / 2 {
+        for u in 0..n {
+            edges.push((u, (u + j) % n));
+        }
+    }
+    let mut present: HashSet<_> = edges.iter().map(|&(a, b)| key(a, b)).collect();
+    let mut degree = vec![0; n];
+    for &(a, b) in &edges {
+        degree[a] += 1;
+        if !Ty::is_directed() {
+            degree[b] += 1;
+        }
+    }
+    for edge in &mut edges {
+        let (u, v) = *edge;
+        // skip nodes that are already connected to all others
+        if rng.next_f64() >= beta || degree[u] >= n - 1 {
+            continue;
+        }
+        let w = loop {
+            let w = rng.below(n);
+            if w != u && !present.contains(&key(u, w)) {
+                break w;
+            }
+        };
+        present.remove(&key(u, v));
+        present.insert(key(u, w));
+        if !Ty::is_directed() {
+            degree[v] -= 1;
+            degree[w] += 1;
+        }
+        *edge = (u, w);
+    }
+    let mut g = empty_graph(n, edges.len());
+    for (a, b) in edges {
+        g.add_edge(NodeIndex::new(a), NodeIndex::new(b), ());
+    }
+    g
+}
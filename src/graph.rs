@@ -1,10 +1,17 @@
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::iter;
 use std::marker::PhantomData;
+use std::mem;
 use std::ops::{Index, IndexMut, Range};
 use std::slice;
 use std::cmp;
+use std::collections::{HashMap, VecDeque};
 
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use dot::{self, Dialect};
 use Direction::{Incoming, Outgoing};
 
 // Index into the NodeIndex and EdgeIndex arrays
@@ -33,11 +40,13 @@ impl Direction {
         }
     }
 }
-const DIRECTIONS: [Direction; 2] = [Outgoing, Incoming];
+pub(crate) const DIRECTIONS: [Direction; 2] = [Outgoing, Incoming];
 
 /// Marker type for directed graphs
+#[derive(Clone, Copy, Debug)]
 pub struct Directed;
 /// Marker type for undirected graphs
+#[derive(Clone, Copy, Debug)]
 pub struct Undirected;
 /// Edge type: determines whether a graph has directed edges or not
 pub trait EdgeType {
@@ -75,7 +84,7 @@ where
         (s, t, E::default())
     }
 }
-impl<'a, E, Ix> IntoWeightedEdge<E> for (Ix, Ix, &'a E)
+impl<E, Ix> IntoWeightedEdge<E> for (Ix, Ix, &E)
 where
     E: Clone,
 {
@@ -85,7 +94,7 @@ where
         (a, b, c.clone())
     }
 }
-impl<'a, E, Ix> IntoWeightedEdge<E> for &'a (Ix, Ix)
+impl<E, Ix> IntoWeightedEdge<E> for &(Ix, Ix)
 where
     Ix: Copy,
     E: Default,
@@ -96,7 +105,7 @@ where
         (s, t, E::default())
     }
 }
-impl<'a, E, Ix> IntoWeightedEdge<E> for &'a (Ix, Ix, E)
+impl<E, Ix> IntoWeightedEdge<E> for &(Ix, Ix, E)
 where
     Ix: Copy,
     E: Clone,
@@ -154,7 +163,7 @@ pub trait IndexType: Copy + Default + Ord + fmt::Debug + 'static {
     fn max() -> Self;
 }
 macro_rules! impl_index_type {
-    ($t:ty, $i:ident) => {
+    ($t:ty) => {
         impl IndexType for $t {
             fn new(x: usize) -> Self {
                 x as $t
@@ -163,21 +172,31 @@ macro_rules! impl_index_type {
                 *self as usize
             }
             fn max() -> Self {
-                ::std::$i::MAX
+                <$t>::MAX
             }
         }
     };
-    ($a:tt) => {
-        impl_index_type!($a, $a);
-    };
 }
 impl_index_type!(usize);
+impl_index_type!(u64);
 impl_index_type!(u32);
 impl_index_type!(u16);
 impl_index_type!(u8);
 
+// Indices are exactly as large as their index type.
+const _: () = assert!(mem::size_of::<NodeIndex<u8>>() == 1);
+const _: () = assert!(mem::size_of::<NodeIndex<u16>>() == 2);
+const _: () = assert!(mem::size_of::<NodeIndex<u32>>() == 4);
+const _: () = assert!(mem::size_of::<NodeIndex<u64>>() == 8);
+const _: () = assert!(mem::size_of::<NodeIndex<usize>>() == mem::size_of::<usize>());
+const _: () = assert!(mem::size_of::<EdgeIndex<u8>>() == 1);
+const _: () = assert!(mem::size_of::<EdgeIndex<u16>>() == 2);
+const _: () = assert!(mem::size_of::<EdgeIndex<u32>>() == 4);
+const _: () = assert!(mem::size_of::<EdgeIndex<u64>>() == 8);
+const _: () = assert!(mem::size_of::<EdgeIndex<usize>>() == mem::size_of::<usize>());
+
 /// Node identifier
-#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct NodeIndex<Ix = DefaultIx>(Ix);
 impl<Ix: IndexType> NodeIndex<Ix> {
     /// Construct a new `NodeIndex`.
@@ -197,9 +216,19 @@ impl<Ix: IndexType> NodeIndex<Ix> {
         EdgeIndex(self.0)
     }
 }
+impl<Ix: IndexType> From<Ix> for NodeIndex<Ix> {
+    fn from(ix: Ix) -> Self {
+        NodeIndex(ix)
+    }
+}
+impl<Ix: IndexType> From<NodeIndex<Ix>> for usize {
+    fn from(ix: NodeIndex<Ix>) -> Self {
+        ix.index()
+    }
+}
 
 /// Edge identifier
-#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct EdgeIndex<Ix = DefaultIx>(Ix);
 impl<Ix: IndexType> EdgeIndex<Ix> {
     /// Construct a new `EdgeIndex`.
@@ -220,14 +249,73 @@ impl<Ix: IndexType> EdgeIndex<Ix> {
         NodeIndex(self.0)
     }
 }
+impl<Ix: IndexType> From<Ix> for EdgeIndex<Ix> {
+    fn from(ix: Ix) -> Self {
+        EdgeIndex(ix)
+    }
+}
+impl<Ix: IndexType> From<EdgeIndex<Ix>> for usize {
+    fn from(ix: EdgeIndex<Ix>) -> Self {
+        ix.index()
+    }
+}
+
+/// A `NodeIndex` or an `EdgeIndex`, see
+/// [`Graph::index_twice_mut`](struct.Graph.html#method.index_twice_mut).
+///
+/// The trait is sealed, it is only implemented by the index types of this
+/// crate.
+pub trait GraphIndex: Copy + sealed::Sealed {
+    /// The position of the node or edge in the graph.
+    fn index(&self) -> usize;
+    /// Whether this indexes nodes rather than edges.
+    fn is_node_index() -> bool;
+}
+impl<Ix: IndexType> GraphIndex for NodeIndex<Ix> {
+    fn index(&self) -> usize {
+        NodeIndex::index(*self)
+    }
+    fn is_node_index() -> bool {
+        true
+    }
+}
+impl<Ix: IndexType> GraphIndex for EdgeIndex<Ix> {
+    fn index(&self) -> usize {
+        EdgeIndex::index(*self)
+    }
+    fn is_node_index() -> bool {
+        false
+    }
+}
+
+mod sealed {
+    use std::ops::Index;
+
+    pub trait Sealed {}
+
+    /// The weights at two indices, see `Graph::index_twice_mut`.
+    pub trait IndexTwiceMut<T, U>: Index<T> + Index<U> {
+        fn index_twice_mut(
+            &mut self,
+            i: T,
+            j: U,
+        ) -> (
+            &mut <Self as Index<T>>::Output,
+            &mut <Self as Index<U>>::Output,
+        );
+    }
+}
+
+impl<Ix: IndexType> sealed::Sealed for NodeIndex<Ix> {}
+impl<Ix: IndexType> sealed::Sealed for EdgeIndex<Ix> {}
 
 /// The graph's node type.
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub struct Node<N, Ix = DefaultIx> {
     /// Associated node data
     pub data: N,
     /// Next edge in outgoing and incoming edge lists
-    next: [EdgeIndex<Ix>; 2],
+    pub(crate) next: [EdgeIndex<Ix>; 2],
 }
 impl<N, Ix: IndexType> Node<N, Ix> {
     /// Accessor for data structure internals:
@@ -251,14 +339,14 @@ where
 type NodeList<N, Ix> = Vec<Node<N, Ix>>;
 
 /// The graph's edge type.
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub struct Edge<E, Ix = DefaultIx> {
     /// Associated edge data
     pub weight: E,
     /// Next edge in outgoing and incoming edge lists
-    next: [EdgeIndex<Ix>; 2],
+    pub(crate) next: [EdgeIndex<Ix>; 2],
     /// Start and End node index
-    node: [NodeIndex<Ix>; 2],
+    pub(crate) node: [NodeIndex<Ix>; 2],
 }
 impl<E, Ix: IndexType> Edge<E, Ix> {
     /// Accessor for data structure internatls: the next edge for the given direction
@@ -475,7 +563,12 @@ impl<N, E, Ty: EdgeType, Ix: IndexType> Graph<N, E, Ty, Ix> {
             next: [EdgeIndex::end(), EdgeIndex::end()],
         };
         match index_twice(&mut self.nodes, a.index(), b.index()) {
-            Pair::None => panic!("Graph::add_edge(): node indices out of bound"),
+            Pair::None => panic!(
+                "Graph::add_edge(): node a={} b={} out of bound (node_count={})",
+                a.index(),
+                b.index(),
+                self.nodes.len()
+            ),
             Pair::One(an) => {
                 edge.next = an.next;
                 an.next[0] = edge_idx;
@@ -520,6 +613,25 @@ impl<N, E, Ty: EdgeType, Ix: IndexType> Graph<N, E, Ty, Ix> {
     pub fn edge_weight_mut(&mut self, e: EdgeIndex<Ix>) -> Option<&mut E> {
         self.edges.get_mut(e.index()).map(|ed| &mut ed.weight)
     }
+    /// Access the weight of the first edge from `a` to `b` found by
+    /// `.find_edge()`, in either orientation if the graph is undirected.
+    ///
+    /// Also available with indexing syntax: `&graph[(a, b)]`.
+    pub fn edge_weight_between(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> Option<&E> {
+        let e = self.find_edge(a, b)?;
+        self.edge_weight(e)
+    }
+    /// Access the weight of the first edge from `a` to `b`, mutably.
+    ///
+    /// Also available with indexing syntax: `&mut graph[(a, b)]`.
+    pub fn edge_weight_between_mut(
+        &mut self,
+        a: NodeIndex<Ix>,
+        b: NodeIndex<Ix>,
+    ) -> Option<&mut E> {
+        let e = self.find_edge(a, b)?;
+        self.edge_weight_mut(e)
+    }
     /// Access the source and target nodes for `e`.
     pub fn edge_endpoints(&self, e: EdgeIndex<Ix>) -> Option<(NodeIndex<Ix>, NodeIndex<Ix>)> {
         self.edges
@@ -644,7 +756,7 @@ impl<N, E, Ty: EdgeType, Ix: IndexType> Graph<N, E, Ty, Ix> {
     /// not borrow from the graph.
     ///
     /// [1]: struct.Neighbors.html#method.detach
-    pub fn neighbors(&self, a: NodeIndex<Ix>) -> Neighbors<E, Ix> {
+    pub fn neighbors(&self, a: NodeIndex<Ix>) -> Neighbors<'_, E, Ix> {
         self.neighbors_directed(a, Outgoing)
     }
     /// Return an iterator of all neighbors that have an edge between them and `a`,
@@ -666,7 +778,7 @@ impl<N, E, Ty: EdgeType, Ix: IndexType> Graph<N, E, Ty, Ix> {
     /// doesn't borrow from the graph.
     ///
     /// [1]: struct.Neighbors.html#method.detach
-    pub fn neighbors_directed(&self, a: NodeIndex<Ix>, dir: Direction) -> Neighbors<E, Ix> {
+    pub fn neighbors_directed(&self, a: NodeIndex<Ix>, dir: Direction) -> Neighbors<'_, E, Ix> {
         let mut iter = self.neighbors_undirected(a);
         if self.is_directed() {
             let k = dir.index();
@@ -687,7 +799,7 @@ impl<N, E, Ty: EdgeType, Ix: IndexType> Graph<N, E, Ty, Ix> {
     /// that doesn't borrow from the graph.
     ///
     /// [1]: struct.Neighbors.html#method.detach
-    pub fn neighbors_undirected(&self, a: NodeIndex<Ix>) -> Neighbors<E, Ix> {
+    pub fn neighbors_undirected(&self, a: NodeIndex<Ix>) -> Neighbors<'_, E, Ix> {
         Neighbors {
             skip_start: a,
             edges: &self.edges,
@@ -697,6 +809,26 @@ impl<N, E, Ty: EdgeType, Ix: IndexType> Graph<N, E, Ty, Ix> {
             },
         }
     }
+    /// Return an iterator of the neighbors of `a` in the specified direction,
+    /// together with the weight of the connecting edge.
+    ///
+    /// Yields the same neighbors in the same order as
+    /// [`.neighbors_directed(a, dir)`](#method.neighbors_directed), in a
+    /// single pass over the edge list.
+    ///
+    /// Produces an empty iterator if the node doesn't exist.<br>
+    /// Iterator element type is `(NodeIndex<Ix>, &E)`.
+    pub fn neighbors_weighted(
+        &self,
+        a: NodeIndex<Ix>,
+        dir: Direction,
+    ) -> impl Iterator<Item = (NodeIndex<Ix>, &E)> {
+        let incoming = self.is_directed() && dir == Incoming;
+        self.edges_directed(a, dir).map(move |e| {
+            let other = if incoming { e.source() } else { e.target() };
+            (other, e.weight())
+        })
+    }
     /// Return an iterator of all edges of `a`.
     ///
     /// `Directed`: Outgoing edges from `a`.
@@ -704,7 +836,7 @@ impl<N, E, Ty: EdgeType, Ix: IndexType> Graph<N, E, Ty, Ix> {
     ///
     /// Produces an empty iterator if the node doesn't exist.<br>
     /// Iterator element type is `EdgeReference<E, Ix>`.
-    pub fn edges(&self, a: NodeIndex<Ix>) -> Edges<E, Ty, Ix> {
+    pub fn edges(&self, a: NodeIndex<Ix>) -> Edges<'_, E, Ty, Ix> {
         self.edges_directed(a, Outgoing)
     }
     /// Return an iterator of all edges of `a`, in the specified direction.
@@ -715,7 +847,7 @@ impl<N, E, Ty: EdgeType, Ix: IndexType> Graph<N, E, Ty, Ix> {
     ///
     /// Produces an empty iterator if the node doesn't exist.<br>
     /// Iterator element type is `EdgeReference<E, Ix>`.
-    pub fn edges_directed(&self, a: NodeIndex<Ix>, dir: Direction) -> Edges<E, Ty, Ix> {
+    pub fn edges_directed(&self, a: NodeIndex<Ix>, dir: Direction) -> Edges<'_, E, Ty, Ix> {
         let mut iter = self.edges_undirected(a);
         if self.is_directed() {
             iter.direction = Some(dir);
@@ -731,7 +863,7 @@ impl<N, E, Ty: EdgeType, Ix: IndexType> Graph<N, E, Ty, Ix> {
     ///
     /// Produces an empty iterator if the node doesn't exist.<br>
     /// Iterator element type is `EdgeReference<E, Ix>`.
-    pub fn edges_undirected(&self, a: NodeIndex<Ix>) -> Edges<E, Ty, Ix> {
+    pub fn edges_undirected(&self, a: NodeIndex<Ix>) -> Edges<'_, E, Ty, Ix> {
         Edges {
             skip_start: a,
             edges: &self.edges,
@@ -821,7 +953,7 @@ impl<N, E, Ty: EdgeType, Ix: IndexType> Graph<N, E, Ty, Ix> {
     ///
     /// For a graph with undirected edges, this equals `.sink_nodes()` in
     /// returning an iterator over all nodes with no edges to or from them.
-    pub fn source_nodes(&self) -> Externals<N, Ty, Ix> {
+    pub fn source_nodes(&self) -> Externals<'_, N, Ty, Ix> {
         self.externals(Incoming)
     }
     /// Return an iterator over the sink nodes of the graph/ the nodes
@@ -831,9 +963,22 @@ impl<N, E, Ty: EdgeType, Ix: IndexType> Graph<N, E, Ty, Ix> {
     ///
     /// For a graph with undirected edges, this equals `.source_nodes()` in
     /// returning an iterator over all nodes with no edges to or from them.
-    pub fn sink_nodes(&self) -> Externals<N, Ty, Ix> {
+    pub fn sink_nodes(&self) -> Externals<'_, N, Ty, Ix> {
         self.externals(Outgoing)
     }
+    /// Return an iterator over the nodes without any edges, neither to nor
+    /// from them.
+    ///
+    /// For a graph with undirected edges, this equals `.source_nodes()`.
+    ///
+    /// The whole iteration computes in **O(|V|)** time.
+    pub fn isolated_nodes(&self) -> impl Iterator<Item = NodeIndex<Ix>> + '_ {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter(|&(_, node)| node.next == [EdgeIndex::end(), EdgeIndex::end()])
+            .map(|(i, _)| NodeIndex::new(i))
+    }
     /// Return an iterator over either the nodes without edges
     /// to them (`Incoming`) or from them (`Outgoing`).
     ///
@@ -845,7 +990,7 @@ impl<N, E, Ty: EdgeType, Ix: IndexType> Graph<N, E, Ty, Ix> {
     /// just nodes without edges.
     ///
     /// The whole iteration computes in **O(|V|)** time.
-    pub fn externals(&self, dir: Direction) -> Externals<N, Ty, Ix> {
+    pub fn externals(&self, dir: Direction) -> Externals<'_, N, Ty, Ix> {
         Externals {
             iter: self.nodes.iter().enumerate(),
             dir,
@@ -859,11 +1004,31 @@ impl<N, E, Ty: EdgeType, Ix: IndexType> Graph<N, E, Ty, Ix> {
             ty: PhantomData,
         }
     }
+    /// Return an iterator over the indices of the nodes whose weight
+    /// satisfies `predicate`, in index order.
+    pub fn node_indices_where<F>(&self, predicate: F) -> FilteredNodeIndices<'_, N, Ix, F>
+    where
+        F: Fn(&N) -> bool,
+    {
+        FilteredNodeIndices {
+            nodes: self.nodes.iter().enumerate(),
+            predicate,
+        }
+    }
+    /// Return an iterator yielding immutable access to all node weights.
+    ///
+    /// The order in which weights are yielded
+    /// matches the order of their node indices.
+    pub fn node_weights(&self) -> NodeWeights<'_, N, Ix> {
+        NodeWeights {
+            nodes: self.nodes.iter()
+        }
+    }
     /// Return an iterator yielding mutable access to all node weights.
     ///
     /// The order in which weighs are yielded
     /// matches the order of their node indices.
-    pub fn node_weights_mut(&mut self) -> NodeWeightsMut<N, Ix> {
+    pub fn node_weights_mut(&mut self) -> NodeWeightsMut<'_, N, Ix> {
         NodeWeightsMut {
             nodes: self.nodes.iter_mut()
         }
@@ -875,19 +1040,37 @@ impl<N, E, Ty: EdgeType, Ix: IndexType> Graph<N, E, Ty, Ix> {
             ty: PhantomData,
         }
     }
+    /// Create an iterator over all nodes and their data, in indexed order.
+    ///
+    /// Also available as `for (a, data) in &graph`.<br>
+    /// Iterator element type is `(NodeIndex<Ix>, &N)`.
+    pub fn node_references(&self) -> NodeReferences<'_, N, Ix> {
+        NodeReferences {
+            iter: self.nodes.iter().enumerate(),
+        }
+    }
     /// Create an iterator over all edges, in indexed order.
     ///
     /// Iterator element type is `EdgeReference<E, Ix>`.
-    pub fn edge_references(&self) -> EdgeReferences<E, Ix> {
+    pub fn edge_references(&self) -> EdgeReferences<'_, E, Ix> {
         EdgeReferences {
             iter: self.edges.iter().enumerate()
         }
     }
+    /// Return an iterator yielding immutable access to all edge weights.
+    ///
+    /// The order in which weights are yielded
+    /// matches the order of their edge indices.
+    pub fn edge_weights(&self) -> EdgeWeights<'_, E, Ix> {
+        EdgeWeights {
+            edges: self.edges.iter()
+        }
+    }
     /// Return an iterator yielding mutable access to all edge weights.
     ///
     /// The order in which weights are yielded
     /// matches the order of their edge indices
-    pub fn edge_weights_mut(&mut self) -> EdgeWeightsMut<E, Ix> {
+    pub fn edge_weights_mut(&mut self) -> EdgeWeightsMut<'_, E, Ix> {
         EdgeWeightsMut {
             edges: self.edges.iter_mut()
         }
@@ -923,12 +1106,20 @@ impl<N, E, Ty: EdgeType, Ix: IndexType> Graph<N, E, Ty, Ix> {
             }
         }
     }
-    /// Accessor for data structure internals: the next edge in the given direction.
-    pub fn next_edge(&self, a: NodeIndex<Ix>, dir: Direction) -> Option<EdgeIndex<Ix>> {
-        match self.edges.get(a.index()) {
+    /// Accessor for data structure internals: the edge after `e` in the
+    /// edge list of its endpoint in the given direction.
+    ///
+    /// Starting from `.first_edge(a, dir)`, this walks all edges of `a`:
+    /// for a directed graph the edges from `a` (`Outgoing`) or to `a`
+    /// (`Incoming`), for an undirected graph the ones where `a` is the first
+    /// (`Outgoing`) or second (`Incoming`) endpoint.
+    ///
+    /// Return `None` at the end of the list or if `e` doesn't exist.
+    pub fn next_edge(&self, e: EdgeIndex<Ix>, dir: Direction) -> Option<EdgeIndex<Ix>> {
+        match self.edges.get(e.index()) {
             None => None,
-            Some(node) => {
-                let edix = node.next[dir.index()];
+            Some(edge) => {
+                let edix = edge.next[dir.index()];
                 if edix == EdgeIndex::end() {
                     None
                 } else {
@@ -938,7 +1129,39 @@ impl<N, E, Ty: EdgeType, Ix: IndexType> Graph<N, E, Ty, Ix> {
         }
     }
 
-    // pub fn index_twice_mut<T, U>(&mut self, i: T, j: U) -> (&mut <Self as Index<T>>::Output, &mut <Self as Index<U>>::Output) {}
+    /// Index the `Graph` by two indices, any of which can be a node index
+    /// or an edge index, to access two weights mutably at the same time.
+    ///
+    /// ```
+    /// use graphs::*;
+    ///
+    /// let mut g = DiGraph::<u32, ()>::new();
+    /// let a = g.add_node(5);
+    /// let b = g.add_node(2);
+    /// {
+    ///     let (x, y) = g.index_twice_mut(a, b);
+    ///     *y += *x;
+    ///     *x = 0;
+    /// }
+    /// assert_eq!((g[a], g[b]), (0, 7));
+    /// ```
+    ///
+    /// **Panics** if the indices are equal or if they are out of bounds.
+    pub fn index_twice_mut<T, U>(
+        &mut self,
+        i: T,
+        j: U,
+    ) -> (
+        &mut <Self as Index<T>>::Output,
+        &mut <Self as Index<U>>::Output,
+    )
+    where
+        Self: sealed::IndexTwiceMut<T, U>,
+        T: GraphIndex,
+        U: GraphIndex,
+    {
+        sealed::IndexTwiceMut::index_twice_mut(self, i, j)
+    }
 
     /// Reverse the direction of all edges.
     pub fn reverse(&mut self) {
@@ -950,6 +1173,229 @@ impl<N, E, Ty: EdgeType, Ix: IndexType> Graph<N, E, Ty, Ix> {
             node.next.swap(0, 1);
         }
     }
+    /// Return a copy of the graph with the direction of all edges reversed.
+    ///
+    /// Node and edge indices are the same as in `self`.
+    pub fn reversed(&self) -> Self
+    where
+        N: Clone,
+        E: Clone,
+    {
+        let mut g = self.clone();
+        g.reverse();
+        g
+    }
+    /// Return an undirected copy of the graph in which each pair of
+    /// anti-parallel edges `a -> b`, `b -> a` is collapsed into a single edge.
+    ///
+    /// Edges are paired up in index order: every edge is matched with the
+    /// first still unmatched edge running in the opposite direction. The
+    /// weight of the collapsed edge is `merge(&w_first, &w_second)`, where
+    /// `w_first` is the weight of the edge with the lower index, and it takes
+    /// that edge's place in the edge order. Unmatched edges and self-loops are
+    /// copied unchanged. Node indices are the same as in `self`.
+    ///
+    /// Use `.into_edge_type::<Undirected>()` to keep all edges instead.
+    pub fn to_undirected<F>(&self, mut merge: F) -> UnGraph<N, E, Ix>
+    where
+        N: Clone,
+        E: Clone,
+        F: FnMut(&E, &E) -> E,
+    {
+        let mut unmatched: HashMap<(usize, usize), VecDeque<usize>> = HashMap::new();
+        let mut partner = vec![None; self.edge_count()];
+        for (i, edge) in self.edges.iter().enumerate() {
+            let (a, b) = (edge.source().index(), edge.target().index());
+            if a != b {
+                if let Some(j) = unmatched.get_mut(&(b, a)).and_then(|q| q.pop_front()) {
+                    partner[j] = Some(i);
+                    partner[i] = Some(j);
+                    continue;
+                }
+            }
+            unmatched.entry((a, b)).or_default().push_back(i);
+        }
+        let mut g = Graph::with_capacity(self.node_count(), self.edge_count());
+        for node in &self.nodes {
+            g.add_node(node.data.clone());
+        }
+        for (i, edge) in self.edges.iter().enumerate() {
+            let weight = match partner[i] {
+                None => edge.weight.clone(),
+                Some(j) if i < j => merge(&edge.weight, &self.edges[j].weight),
+                Some(_) => continue,
+            };
+            g.add_edge(edge.source(), edge.target(), weight);
+        }
+        g
+    }
+    /// Return `true` if the graph has neither self-loops nor parallel edges.
+    ///
+    /// In an undirected graph, `a -- b` and `b -- a` are parallel.
+    pub fn is_simple(&self) -> bool {
+        self.self_loops().next().is_none() && self.parallel_edges().next().is_none()
+    }
+    /// Return an iterator over the indices of all self-loops, in index order.
+    pub fn self_loops(&self) -> impl Iterator<Item = EdgeIndex<Ix>> + '_ {
+        self.edges
+            .iter()
+            .enumerate()
+            .filter(|&(_, edge)| edge.source() == edge.target())
+            .map(|(i, _)| EdgeIndex::new(i))
+    }
+    /// Return an iterator over all parallel edges.
+    ///
+    /// For every edge that runs between the same endpoints as an edge with a
+    /// lower index, it yields `(first, parallel)`, where `first` is the
+    /// lowest-indexed edge of that group. In an undirected graph, `a -- b`
+    /// and `b -- a` are parallel.
+    ///
+    /// Computes in **O(|E|)** time.
+    pub fn parallel_edges(&self) -> impl Iterator<Item = (EdgeIndex<Ix>, EdgeIndex<Ix>)> {
+        let mut first = HashMap::new();
+        let mut parallel = Vec::new();
+        for (i, edge) in self.edges.iter().enumerate() {
+            let j = *first.entry(self.edge_key(edge)).or_insert(i);
+            if i != j {
+                parallel.push((EdgeIndex::new(j), EdgeIndex::new(i)));
+            }
+        }
+        parallel.into_iter()
+    }
+    /// Remove all self-loops and return how many were removed.
+    ///
+    /// Like `.remove_edge()`, this invalidates edge indices.
+    pub fn remove_self_loops(&mut self) -> usize {
+        let mut removed = 0;
+        // Walking backwards, the edge swapped into a freed slot has already
+        // been looked at.
+        for i in (0..self.edge_count()).rev() {
+            if self.edges[i].source() == self.edges[i].target() {
+                self.remove_edge(EdgeIndex::new(i));
+                removed += 1;
+            }
+        }
+        removed
+    }
+    /// Collapse every group of parallel edges into a single edge, whose
+    /// weight is obtained by folding the group's weights with `merge` in
+    /// index order. Return how many edges were removed.
+    ///
+    /// In an undirected graph, `a -- b` and `b -- a` are parallel. The
+    /// remaining edges keep the relative order and orientation of the first
+    /// edge of their group, but edge indices are invalidated.
+    ///
+    /// Computes in **O(|V| + |E|)** time.
+    pub fn dedup_edges<F>(&mut self, mut merge: F) -> usize
+    where
+        F: FnMut(E, E) -> E,
+    {
+        let old_count = self.edge_count();
+        let mut position: HashMap<(usize, usize), usize> = HashMap::new();
+        let mut groups: Vec<(NodeIndex<Ix>, NodeIndex<Ix>, Option<E>)> = Vec::new();
+        for edge in ::std::mem::take(&mut self.edges) {
+            let key = self.edge_key(&edge);
+            if let Some(&p) = position.get(&key) {
+                let weight = groups[p].2.take().unwrap();
+                groups[p].2 = Some(merge(weight, edge.weight));
+                continue;
+            }
+            position.insert(key, groups.len());
+            groups.push((edge.source(), edge.target(), Some(edge.weight)));
+        }
+        for node in &mut self.nodes {
+            node.next = [EdgeIndex::end(), EdgeIndex::end()];
+        }
+        self.edges.reserve(groups.len());
+        for (a, b, weight) in groups {
+            self.add_edge(a, b, weight.unwrap());
+        }
+        old_count - self.edge_count()
+    }
+    /// Reorder the nodes by the key `key` returns for each node, keeping the
+    /// relative order of nodes with equal keys.
+    ///
+    /// All edges are kept and their endpoints are updated, edge indices stay
+    /// the same.
+    ///
+    /// Return the mapping from old to new node indices: the node formerly at
+    /// index `i` is now at `mapping[i]`.
+    ///
+    /// Computes in **O(|V| log |V| + |E|)** time.
+    pub fn sort_nodes_by_key<K, F>(&mut self, mut key: F) -> Vec<NodeIndex<Ix>>
+    where
+        K: Ord,
+        F: FnMut(NodeIndex<Ix>, &N) -> K,
+    {
+        let keys: Vec<K> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| key(NodeIndex::new(i), &node.data))
+            .collect();
+        let mut order: Vec<usize> = (0..self.node_count()).collect();
+        order.sort_by(|&i, &j| keys[i].cmp(&keys[j]));
+        let mut mapping = vec![NodeIndex::end(); self.node_count()];
+        for (new, &old) in order.iter().enumerate() {
+            mapping[old] = NodeIndex::new(new);
+        }
+        self.nodes = permute(::std::mem::take(&mut self.nodes), &order);
+        for edge in &mut self.edges {
+            edge.node = [mapping[edge.node[0].index()], mapping[edge.node[1].index()]];
+        }
+        mapping
+    }
+    /// Reorder the edges by the key `key` returns for each edge, keeping the
+    /// relative order of edges with equal keys.
+    ///
+    /// Node indices and the order of each node's adjacency lists stay the same.
+    ///
+    /// Return the mapping from old to new edge indices: the edge formerly at
+    /// index `i` is now at `mapping[i]`.
+    ///
+    /// Computes in **O(|E| log |E| + |V|)** time.
+    pub fn sort_edges_by_key<K, F>(&mut self, mut key: F) -> Vec<EdgeIndex<Ix>>
+    where
+        K: Ord,
+        F: FnMut(EdgeIndex<Ix>, &E) -> K,
+    {
+        let keys: Vec<K> = self
+            .edges
+            .iter()
+            .enumerate()
+            .map(|(i, edge)| key(EdgeIndex::new(i), &edge.weight))
+            .collect();
+        let mut order: Vec<usize> = (0..self.edge_count()).collect();
+        order.sort_by(|&i, &j| keys[i].cmp(&keys[j]));
+        let mut mapping = vec![EdgeIndex::end(); self.edge_count()];
+        for (new, &old) in order.iter().enumerate() {
+            mapping[old] = EdgeIndex::new(new);
+        }
+        let remap = |e: EdgeIndex<Ix>| {
+            if e == EdgeIndex::end() {
+                e
+            } else {
+                mapping[e.index()]
+            }
+        };
+        self.edges = permute(::std::mem::take(&mut self.edges), &order);
+        for edge in &mut self.edges {
+            edge.next = [remap(edge.next[0]), remap(edge.next[1])];
+        }
+        for node in &mut self.nodes {
+            node.next = [remap(node.next[0]), remap(node.next[1])];
+        }
+        mapping
+    }
+    /// The endpoints of `edge`, ordered if the graph is undirected.
+    fn edge_key(&self, edge: &Edge<E, Ix>) -> (usize, usize) {
+        let (a, b) = (edge.source().index(), edge.target().index());
+        if self.is_directed() {
+            (a, b)
+        } else {
+            (cmp::min(a, b), cmp::max(a, b))
+        }
+    }
     /// Remove all nodes and edges.
     pub fn clear(&mut self) {
         self.nodes.clear();
@@ -1061,6 +1507,50 @@ impl<N, E, Ty: EdgeType, Ix: IndexType> Graph<N, E, Ty, Ix> {
             self.add_edge(source, target, weight);
         }
     }
+    /// Move all nodes and edges of `other` into `self`.
+    ///
+    /// The nodes of `other` keep their relative order and are shifted by
+    /// the old node count of `self`, the same applies to its edges.
+    /// Parallel edges in `other` are carried over unchanged.
+    ///
+    /// Return the range of node indices the nodes of `other` now occupy.
+    ///
+    /// Computes in **O(|V'| + |E'|)** time, where **|V'|** and **|E'|** are
+    /// the node and edge counts of `other`.
+    ///
+    /// **Panics** if the combined graph exceeds the maximum number of nodes
+    /// or edges for its index type.
+    pub fn append(&mut self, other: Graph<N, E, Ty, Ix>) -> Range<usize> {
+        let node_offset = self.node_count();
+        let edge_offset = self.edge_count();
+        assert!(node_offset + other.node_count() <= <Ix as IndexType>::max().index());
+        assert!(edge_offset + other.edge_count() <= <Ix as IndexType>::max().index());
+        let shift_edge = |e: EdgeIndex<Ix>| {
+            if e == EdgeIndex::end() {
+                e
+            } else {
+                EdgeIndex::new(e.index() + edge_offset)
+            }
+        };
+        let shift_node = |n: NodeIndex<Ix>| NodeIndex::new(n.index() + node_offset);
+
+        self.nodes.reserve(other.node_count());
+        self.edges.reserve(other.edge_count());
+        for node in other.nodes {
+            self.nodes.push(Node {
+                data: node.data,
+                next: [shift_edge(node.next[0]), shift_edge(node.next[1])],
+            });
+        }
+        for edge in other.edges {
+            self.edges.push(Edge {
+                weight: edge.weight,
+                next: [shift_edge(edge.next[0]), shift_edge(edge.next[1])],
+                node: [shift_node(edge.node[0]), shift_node(edge.node[1])],
+            });
+        }
+        node_offset..self.node_count()
+    }
     // pub fn map ...
     // pub fn filter_map ...
 
@@ -1075,6 +1565,265 @@ impl<N, E, Ty: EdgeType, Ix: IndexType> Graph<N, E, Ty, Ix> {
             ty: PhantomData,
         }
     }
+    /// Return the complement of the graph: a graph with the same nodes
+    /// (data cloned) and an edge between every pair of distinct nodes that
+    /// are *not* adjacent in `self`.
+    ///
+    /// For `Directed` graphs ordered pairs are considered, for `Undirected`
+    /// graphs unordered pairs. The result never contains self-loops.
+    ///
+    /// Parallel edges in `self` are deduplicated: a pair of nodes counts as
+    /// adjacent no matter how many edges connect it. Self-loops in `self` are
+    /// ignored.
+    ///
+    /// Computes in **O(|V|² + |E|)** time and uses **O(|V|²)** extra space.
+    pub fn complement(&self) -> Graph<N, (), Ty, Ix>
+    where
+        N: Clone,
+    {
+        let n = self.node_count();
+        let mut adjacent = vec![false; n * n];
+        for edge in &self.edges {
+            let (a, b) = (edge.source().index(), edge.target().index());
+            adjacent[a * n + b] = true;
+            if !self.is_directed() {
+                adjacent[b * n + a] = true;
+            }
+        }
+        let mut g = Graph::with_capacity(n, 0);
+        for node in &self.nodes {
+            g.add_node(node.data.clone());
+        }
+        for a in 0..n {
+            let start = if self.is_directed() { 0 } else { a + 1 };
+            for b in start..n {
+                if a != b && !adjacent[a * n + b] {
+                    g.add_edge(NodeIndex::new(a), NodeIndex::new(b), ());
+                }
+            }
+        }
+        g
+    }
+    /// Return the weighted adjacency matrix of the graph, as a flat row-major
+    /// `n × n` vector where `n` is the node count.
+    ///
+    /// Entry `(i, j)` is the sum of the weights of all edges from `i` to `j`,
+    /// so parallel edges add up. For undirected graphs the matrix is
+    /// symmetric; a self-loop contributes its weight once to the diagonal.
+    ///
+    /// ```
+    /// use graphs::*;
+    ///
+    /// let mut g = UnGraph::<(), f64>::new_undirected();
+    /// let a = g.add_node(());
+    /// let b = g.add_node(());
+    /// g.add_edge(a, b, 1.0);
+    /// g.add_edge(b, a, 0.5);
+    /// g.add_edge(b, b, 2.0);
+    /// assert_eq!(g.to_adjacency_matrix(), vec![0.0, 1.5, 1.5, 2.0]);
+    /// ```
+    pub fn to_adjacency_matrix(&self) -> Vec<f64>
+    where
+        E: Clone + Into<f64>,
+    {
+        let n = self.node_count();
+        let mut matrix = vec![0.0; n * n];
+        for edge in &self.edges {
+            let (a, b) = (edge.source().index(), edge.target().index());
+            let w = edge.weight.clone().into();
+            matrix[a * n + b] += w;
+            if !self.is_directed() && a != b {
+                matrix[b * n + a] += w;
+            }
+        }
+        matrix
+    }
+    /// Return the weighted Laplacian matrix `L = D - A` of the graph, as a
+    /// flat row-major `n × n` vector.
+    ///
+    /// `A` is the [adjacency matrix](#method.to_adjacency_matrix) and `D` the
+    /// diagonal matrix of the weighted degrees, out-degrees for directed
+    /// graphs. Parallel edges add up. Self-loops are left out: they would add
+    /// the same amount to `D` and `A`, so by the usual convention they don't
+    /// change the Laplacian. Every row of the result sums to zero.
+    pub fn to_laplacian(&self) -> Vec<f64>
+    where
+        E: Clone + Into<f64>,
+    {
+        let n = self.node_count();
+        let mut matrix = vec![0.0; n * n];
+        for edge in &self.edges {
+            let (a, b) = (edge.source().index(), edge.target().index());
+            if a == b {
+                continue;
+            }
+            let w = edge.weight.clone().into();
+            matrix[a * n + b] -= w;
+            matrix[a * n + a] += w;
+            if !self.is_directed() {
+                matrix[b * n + a] -= w;
+                matrix[b * n + b] += w;
+            }
+        }
+        matrix
+    }
+    /// Return the incidence matrix of the graph, as a flat row-major
+    /// `n × m` vector where `n` is the node count and `m` the edge count.
+    ///
+    /// Column `e` describes edge `e`. For directed graphs it is `-1` at the
+    /// source and `1` at the target, so the column of a self-loop is all
+    /// zeros. For undirected graphs it is `1` at both endpoints, and `2` at
+    /// the node of a self-loop. Weights are ignored.
+    pub fn to_incidence_matrix(&self) -> Vec<f64> {
+        let m = self.edge_count();
+        let mut matrix = vec![0.0; self.node_count() * m];
+        for (e, edge) in self.edges.iter().enumerate() {
+            let (a, b) = (edge.source().index(), edge.target().index());
+            if self.is_directed() {
+                matrix[a * m + e] -= 1.0;
+            } else {
+                matrix[a * m + e] += 1.0;
+            }
+            matrix[b * m + e] += 1.0;
+        }
+        matrix
+    }
+    /// Create a graph with `n` nodes from a dense adjacency matrix, given as
+    /// a flat row-major `n × n` slice.
+    ///
+    /// Every entry `(i, j)` that isn't `E::default()` (zero for numbers)
+    /// becomes an edge from node `i` to node `j` with the entry as weight.
+    /// For undirected graphs only the upper triangle, `i <= j`, is read.
+    /// Nodes get `N::default()` as data.
+    ///
+    /// ```
+    /// use graphs::*;
+    ///
+    /// let g = DiGraph::<(), u32>::from_adjacency_matrix(2, &[0, 3, 1, 0]);
+    /// assert_eq!(g.edge_count(), 2);
+    /// assert_eq!(g.to_adjacency_matrix(), vec![0.0, 3.0, 1.0, 0.0]);
+    /// ```
+    ///
+    /// **Panics** if `data.len()` is not `n * n`.
+    pub fn from_adjacency_matrix(n: usize, data: &[E]) -> Self
+    where
+        N: Default,
+        E: Clone + Default + PartialEq,
+    {
+        assert_eq!(
+            data.len(),
+            n * n,
+            "Graph::from_adjacency_matrix: matrix is not {} × {}",
+            n,
+            n
+        );
+        let zero = E::default();
+        let mut g = Graph::with_capacity(n, 0);
+        for _ in 0..n {
+            g.add_node(N::default());
+        }
+        for a in 0..n {
+            let start = if g.is_directed() { 0 } else { a };
+            for b in start..n {
+                let w = &data[a * n + b];
+                if *w != zero {
+                    g.add_edge(NodeIndex::new(a), NodeIndex::new(b), w.clone());
+                }
+            }
+        }
+        g
+    }
+    /// Render the graph in the DOT format of Graphviz.
+    ///
+    /// Directed graphs produce `digraph G { ... }` with `->` edges, undirected
+    /// graphs `graph G { ... }` with `--` edges. Nodes are named by their
+    /// index; `node_fmt` and `edge_fmt` produce the `label` attributes, which
+    /// are quoted and escaped.
+    ///
+    /// ```
+    /// use graphs::*;
+    ///
+    /// let mut g = Graph::<&str, u32>::new();
+    /// let a = g.add_node("a");
+    /// let b = g.add_node("b");
+    /// g.add_edge(a, b, 7);
+    /// let dot = g.to_dot(|_, n| n.to_string(), |_, e| e.to_string());
+    /// assert_eq!(
+    ///     dot,
+    ///     "digraph G {\n    0 [label=\"a\"];\n    1 [label=\"b\"];\n    0 -> 1 [label=\"7\"];\n}\n"
+    /// );
+    /// ```
+    pub fn to_dot<FN, FE>(&self, node_fmt: FN, edge_fmt: FE) -> String
+    where
+        FN: Fn(NodeIndex<Ix>, &N) -> String,
+        FE: Fn(EdgeIndex<Ix>, &E) -> String,
+    {
+        let mut s = String::new();
+        dot::write_dot(
+            &mut s,
+            self,
+            Dialect::Named,
+            |w, i, n| w.write_str(&node_fmt(i, n)),
+            Some(|w: &mut dyn fmt::Write, i, e: &E| w.write_str(&edge_fmt(i, e))),
+        )
+        .unwrap();
+        s
+    }
+    /// Render the graph in the DOT format of Graphviz, using the `Display`
+    /// implementations of the node data and edge weights as labels.
+    ///
+    /// See [`.to_dot()`](#method.to_dot).
+    pub fn to_dot_display(&self) -> String
+    where
+        N: fmt::Display,
+        E: fmt::Display,
+    {
+        self.to_dot(|_, n| n.to_string(), |_, e| e.to_string())
+    }
+}
+/// Parallel iteration over the weights, enabled by the `rayon` feature.
+///
+/// The weights are visited in an unspecified order.
+#[cfg(feature = "rayon")]
+impl<N, E, Ty: EdgeType, Ix: IndexType> Graph<N, E, Ty, Ix> {
+    /// Return a parallel iterator over all node weights.
+    pub fn par_node_weights(&self) -> impl ParallelIterator<Item = &N>
+    where
+        N: Sync,
+        Ix: Sync,
+    {
+        self.nodes.par_iter().map(|node| &node.data)
+    }
+    /// Return a parallel iterator yielding mutable access to all node weights.
+    pub fn par_node_weights_mut(&mut self) -> impl ParallelIterator<Item = &mut N>
+    where
+        N: Send,
+        Ix: Send,
+    {
+        self.nodes.par_iter_mut().map(|node| &mut node.data)
+    }
+    /// Return a parallel iterator over all edge weights.
+    pub fn par_edge_weights(&self) -> impl ParallelIterator<Item = &E>
+    where
+        E: Sync,
+        Ix: Sync,
+    {
+        self.edges.par_iter().map(|edge| &edge.weight)
+    }
+    /// Return a parallel iterator yielding mutable access to all edge weights.
+    pub fn par_edge_weights_mut(&mut self) -> impl ParallelIterator<Item = &mut E>
+    where
+        E: Send,
+        Ix: Send,
+    {
+        self.edges.par_iter_mut().map(|edge| &mut edge.weight)
+    }
+}
+/// Move the elements of `v` into a new vector, where position `p` holds the
+/// element formerly at `order[p]`.
+fn permute<T>(v: Vec<T>, order: &[usize]) -> Vec<T> {
+    let mut slots: Vec<Option<T>> = v.into_iter().map(Some).collect();
+    order.iter().map(|&i| slots[i].take().unwrap()).collect()
 }
 // * GRAPH TRAIT IMPLs * //
 impl<N, E, Ty, Ix: IndexType> Clone for Graph<N, E, Ty, Ix>
@@ -1090,6 +1839,66 @@ where
         }
     }
 }
+/// Two graphs are equal if their nodes and edges are equal in index order,
+/// including the internal links of the edge lists.
+///
+/// Graphs with the same nodes and edges added in a different order are not
+/// equal.
+impl<N, E, Ty, Ix> PartialEq for Graph<N, E, Ty, Ix>
+where
+    N: PartialEq,
+    E: PartialEq,
+    Ix: IndexType,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.nodes == other.nodes && self.edges == other.edges
+    }
+}
+impl<N, E, Ty, Ix> Eq for Graph<N, E, Ty, Ix>
+where
+    N: Eq,
+    E: Eq,
+    Ix: IndexType,
+{
+}
+/// Hash the node data in index order, then the source, target and weight
+/// of every edge in index order.
+impl<N, E, Ty, Ix> Hash for Graph<N, E, Ty, Ix>
+where
+    N: Hash,
+    E: Hash,
+    Ix: IndexType,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.nodes.len().hash(state);
+        for node in &self.nodes {
+            node.data.hash(state);
+        }
+        self.edges.len().hash(state);
+        for edge in &self.edges {
+            (edge.source().index(), edge.target().index(), &edge.weight).hash(state);
+        }
+    }
+}
+/// Format the graph in the DOT format of Graphviz, see
+/// [`.to_dot_display()`](struct.Graph.html#method.to_dot_display).
+impl<N, E, Ty, Ix> fmt::Display for Graph<N, E, Ty, Ix>
+where
+    N: fmt::Display,
+    E: fmt::Display,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        dot::write_dot(
+            f,
+            self,
+            Dialect::Named,
+            |w, _, n| write!(w, "{}", n),
+            Some(|w: &mut dyn fmt::Write, _, e: &E| write!(w, "{}", e)),
+        )
+    }
+}
 impl<N, E, Ty, Ix> Default for Graph<N, E, Ty, Ix>
 where
     Ty: EdgeType,
@@ -1149,12 +1958,111 @@ where
         &mut self.edges[index.index()].weight
     }
 }
-// impl<'a, N, E, Ty, Ix> IntoNodeReferences for &'a Graph<N, E, Ty, Ix>
-// where
-// Ty: EdgeType,
-// Ix: IndexType,
-// {
-// }
+impl<N, E, Ty, Ix> sealed::IndexTwiceMut<NodeIndex<Ix>, NodeIndex<Ix>> for Graph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn index_twice_mut(&mut self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> (&mut N, &mut N) {
+        assert!(
+            a != b,
+            "Graph::index_twice_mut(): indices must not be equal"
+        );
+        match index_twice(&mut self.nodes, a.index(), b.index()) {
+            Pair::Both(x, y) => (&mut x.data, &mut y.data),
+            _ => panic!("Graph::index_twice_mut(): node index out of bounds"),
+        }
+    }
+}
+
+impl<N, E, Ty, Ix> sealed::IndexTwiceMut<EdgeIndex<Ix>, EdgeIndex<Ix>> for Graph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn index_twice_mut(&mut self, a: EdgeIndex<Ix>, b: EdgeIndex<Ix>) -> (&mut E, &mut E) {
+        assert!(
+            a != b,
+            "Graph::index_twice_mut(): indices must not be equal"
+        );
+        match index_twice(&mut self.edges, a.index(), b.index()) {
+            Pair::Both(x, y) => (&mut x.weight, &mut y.weight),
+            _ => panic!("Graph::index_twice_mut(): edge index out of bounds"),
+        }
+    }
+}
+
+impl<N, E, Ty, Ix> sealed::IndexTwiceMut<NodeIndex<Ix>, EdgeIndex<Ix>> for Graph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn index_twice_mut(&mut self, a: NodeIndex<Ix>, e: EdgeIndex<Ix>) -> (&mut N, &mut E) {
+        (
+            &mut self.nodes[a.index()].data,
+            &mut self.edges[e.index()].weight,
+        )
+    }
+}
+
+impl<N, E, Ty, Ix> sealed::IndexTwiceMut<EdgeIndex<Ix>, NodeIndex<Ix>> for Graph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn index_twice_mut(&mut self, e: EdgeIndex<Ix>, a: NodeIndex<Ix>) -> (&mut E, &mut N) {
+        (
+            &mut self.edges[e.index()].weight,
+            &mut self.nodes[a.index()].data,
+        )
+    }
+}
+/// Index the `Graph` by a pair of `NodeIndex` to access the weight of the
+/// first edge between them, see `.edge_weight_between()`.
+///
+/// **Panics** if there is no such edge.
+impl<N, E, Ty, Ix> Index<(NodeIndex<Ix>, NodeIndex<Ix>)> for Graph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Output = E;
+    fn index(&self, (a, b): (NodeIndex<Ix>, NodeIndex<Ix>)) -> &E {
+        match self.edge_weight_between(a, b) {
+            Some(weight) => weight,
+            None => panic!("Graph: no edge from {:?} to {:?}", a, b),
+        }
+    }
+}
+/// Index the `Graph` by a pair of `NodeIndex` to access the weight of the
+/// first edge between them, mutably.
+///
+/// **Panics** if there is no such edge.
+impl<N, E, Ty, Ix> IndexMut<(NodeIndex<Ix>, NodeIndex<Ix>)> for Graph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn index_mut(&mut self, (a, b): (NodeIndex<Ix>, NodeIndex<Ix>)) -> &mut E {
+        match self.edge_weight_between_mut(a, b) {
+            Some(weight) => weight,
+            None => panic!("Graph: no edge from {:?} to {:?}", a, b),
+        }
+    }
+}
+/// Iterate over all nodes with their data, see
+/// [`.node_references()`](struct.Graph.html#method.node_references).
+impl<'a, N, E, Ty, Ix> IntoIterator for &'a Graph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Item = (NodeIndex<Ix>, &'a N);
+    type IntoIter = NodeReferences<'a, N, Ix>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.node_references()
+    }
+}
 
 //* NODES *//
 /// An iterator over either the nodes without edges to them or from them.
@@ -1187,6 +2095,76 @@ where
         }
     }
 }
+impl<'a, N, Ty, Ix> iter::FusedIterator for Externals<'a, N, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+}
+/// Iterator over the node indices whose weight satisfies a predicate.
+///
+/// Created with [`.node_indices_where()`](struct.Graph.html#method.node_indices_where).
+pub struct FilteredNodeIndices<'a, N: 'a, Ix: IndexType, F> {
+    nodes: iter::Enumerate<slice::Iter<'a, Node<N, Ix>>>,
+    predicate: F,
+}
+impl<'a, N, Ix, F> Iterator for FilteredNodeIndices<'a, N, Ix, F>
+where
+    Ix: IndexType,
+    F: Fn(&N) -> bool,
+{
+    type Item = NodeIndex<Ix>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let predicate = &self.predicate;
+        self.nodes
+            .find(|&(_, node)| predicate(&node.data))
+            .map(|(i, _)| NodeIndex::new(i))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.nodes.size_hint().1)
+    }
+}
+impl<'a, N, Ix, F> DoubleEndedIterator for FilteredNodeIndices<'a, N, Ix, F>
+where
+    Ix: IndexType,
+    F: Fn(&N) -> bool,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let predicate = &self.predicate;
+        self.nodes
+            .rfind(|&(_, node)| predicate(&node.data))
+            .map(|(i, _)| NodeIndex::new(i))
+    }
+}
+impl<'a, N, Ix, F> iter::FusedIterator for FilteredNodeIndices<'a, N, Ix, F>
+where
+    Ix: IndexType,
+    F: Fn(&N) -> bool,
+{
+}
+/// Iterator yielding immutable access to all node weights.
+pub struct NodeWeights<'a, N: 'a, Ix: IndexType = DefaultIx> {
+    nodes: slice::Iter<'a, Node<N, Ix>>,
+}
+impl<'a, N, Ix> Iterator for NodeWeights<'a, N, Ix>
+where
+    Ix: IndexType,
+{
+    type Item = &'a N;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nodes.next().map(|node| &node.data)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.nodes.size_hint()
+    }
+}
+impl<'a, N, Ix: IndexType> DoubleEndedIterator for NodeWeights<'a, N, Ix> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nodes.next_back().map(|node| &node.data)
+    }
+}
+impl<'a, N, Ix: IndexType> ExactSizeIterator for NodeWeights<'a, N, Ix> {}
+impl<'a, N, Ix: IndexType> iter::FusedIterator for NodeWeights<'a, N, Ix> {}
 /// Iterator yielding mutable access to all node weights.
 pub struct NodeWeightsMut<'a, N: 'a, Ix: IndexType = DefaultIx> {
     nodes: slice::IterMut<'a, Node<N, Ix>>,
@@ -1200,6 +2178,7 @@ where
         self.nodes.next().map(|node| &mut node.data)
     }
 }
+impl<'a, N, Ix: IndexType> iter::FusedIterator for NodeWeightsMut<'a, N, Ix> {}
 /// Iterator over the neighbors of a node.
 ///
 /// Iterator element type is `NodeIndex<Ix>`.
@@ -1243,6 +2222,7 @@ where
         None
     }
 }
+impl<'a, E, Ix: IndexType> iter::FusedIterator for Neighbors<'a, E, Ix> {}
 impl<'a, E, Ix> Clone for Neighbors<'a, E, Ix>
 where
     Ix: IndexType,
@@ -1359,7 +2339,11 @@ where
             .next()
             .map(|(i, node)| (NodeIndex::new(i), &node.data))
     }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
 }
+impl<'a, N, Ix: IndexType> iter::FusedIterator for NodeReferences<'a, N, Ix> {}
 impl<'a, N, Ix> DoubleEndedIterator for NodeReferences<'a, N, Ix>
 where
     Ix: IndexType,
@@ -1386,7 +2370,11 @@ impl<Ix: IndexType> Iterator for NodeIndices<Ix> {
     fn next(&mut self) -> Option<Self::Item> {
         self.r.next().map(NodeIndex::new)
     }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.r.size_hint()
+    }
 }
+impl<Ix: IndexType> iter::FusedIterator for NodeIndices<Ix> {}
 impl<Ix: IndexType> DoubleEndedIterator for NodeIndices<Ix> {
     fn next_back(&mut self) -> Option<Self::Item> {
         self.r.next_back().map(NodeIndex::new)
@@ -1404,7 +2392,7 @@ fn edges_walker_mut<E, Ix>(
     edges: &mut [Edge<E, Ix>],
     next: EdgeIndex<Ix>,
     dir: Direction,
-) -> EdgesWalkerMut<E, Ix>
+) -> EdgesWalkerMut<'_, E, Ix>
 where
     Ix: IndexType,
 {
@@ -1462,11 +2450,7 @@ where
         let i = self.next[0].index();
         match self.edges.get(i) {
             None => {}
-            Some(&Edge {
-                ref node,
-                ref weight,
-                ref next,
-            }) => {
+            Some(Edge { node, weight, next }) => {
                 self.next[0] = next[k];
                 return Some(EdgeReference {
                     index: EdgeIndex::new(i),
@@ -1504,6 +2488,12 @@ where
         None
     }
 }
+impl<'a, E, Ty, Ix> iter::FusedIterator for Edges<'a, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+}
 // fn swap_pair<T>(mut x: [T; 2]) -> [T; 2] {
 //     x.swap(0, 1);
 //     x
@@ -1531,6 +2521,27 @@ pub struct EdgeReference<'a, E: 'a, Ix = DefaultIx> {
     node: [NodeIndex<Ix>; 2],
     weight: &'a E,
 }
+impl<'a, E, Ix: IndexType> EdgeReference<'a, E, Ix> {
+    /// Return the index of the edge.
+    pub fn id(&self) -> EdgeIndex<Ix> {
+        self.index
+    }
+    /// Return the source node index.
+    pub fn source(&self) -> NodeIndex<Ix> {
+        self.node[0]
+    }
+    /// Return the target node index.
+    pub fn target(&self) -> NodeIndex<Ix> {
+        self.node[1]
+    }
+    /// Access the edge's weight.
+    ///
+    /// **NOTE** that this method offers a longer lifetime than
+    /// the trait (unfortunately they don't match yet).
+    pub fn weight(&self) -> &'a E {
+        self.weight
+    }
+}
 impl<'a, E, Ix: IndexType> Clone for EdgeReference<'a, E, Ix> {
     fn clone(&self) -> Self {
         *self
@@ -1559,7 +2570,11 @@ impl<'a, E, Ix: IndexType> Iterator for EdgeReferences<'a, E, Ix> {
             weight: &edge.weight,
         })
     }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
 }
+impl<'a, E, Ix: IndexType> iter::FusedIterator for EdgeReferences<'a, E, Ix> {}
 impl<'a, E, Ix: IndexType> DoubleEndedIterator for EdgeReferences<'a, E, Ix> {
     fn next_back(&mut self) -> Option<Self::Item> {
         self.iter.next_back().map(|(i, edge)| EdgeReference {
@@ -1586,6 +2601,37 @@ impl<Ix: IndexType> Iterator for EdgeIndices<Ix> {
         self.r.size_hint()
     }
 }
+impl<Ix: IndexType> iter::FusedIterator for EdgeIndices<Ix> {}
+impl<Ix: IndexType> DoubleEndedIterator for EdgeIndices<Ix> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.r.next_back().map(EdgeIndex::new)
+    }
+}
+impl<Ix: IndexType> ExactSizeIterator for EdgeIndices<Ix> {}
+
+/// Iterator yielding immutable access to all edge weights.
+pub struct EdgeWeights<'a, E: 'a, Ix: IndexType = DefaultIx> {
+    edges: slice::Iter<'a, Edge<E, Ix>>,
+}
+impl<'a, E, Ix> Iterator for EdgeWeights<'a, E, Ix>
+where
+    Ix: IndexType,
+{
+    type Item = &'a E;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.edges.next().map(|edge| &edge.weight)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.edges.size_hint()
+    }
+}
+impl<'a, E, Ix: IndexType> DoubleEndedIterator for EdgeWeights<'a, E, Ix> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.edges.next_back().map(|edge| &edge.weight)
+    }
+}
+impl<'a, E, Ix: IndexType> ExactSizeIterator for EdgeWeights<'a, E, Ix> {}
+impl<'a, E, Ix: IndexType> iter::FusedIterator for EdgeWeights<'a, E, Ix> {}
 
 /// Iterator yielding mutable access to all edge weights.
 pub struct EdgeWeightsMut<'a, E: 'a, Ix: IndexType = DefaultIx> {
@@ -1603,3 +2649,4 @@ where
         self.edges.size_hint()
     }
 }
+impl<'a, E, Ix: IndexType> iter::FusedIterator for EdgeWeightsMut<'a, E, Ix> {}
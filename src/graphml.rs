@@ -0,0 +1,638 @@
+//! GraphML import and export.
+//!
+//! GraphML is the XML based format spoken by Gephi, yEd and most other graph
+//! visualization tools. Node data and edge weights are mapped to string
+//! attributes, declared as `<key>`s and stored in `<data>` elements.
+use std::collections::{BTreeSet, HashMap};
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::io::{self, BufRead, Write};
+
+use graph::{EdgeType, Graph, IndexType, NodeIndex};
+
+/// The attributes of a node or edge, by attribute name.
+pub type Attributes = HashMap<String, String>;
+
+/// Write `graph` as GraphML, using the `Display` implementations of the node
+/// data and edge weights as their `label` attributes.
+///
+/// ```
+/// use graphs::*;
+/// use graphs::graphml;
+///
+/// let mut g = UnGraph::<&str, u32>::new_undirected();
+/// let a = g.add_node("a");
+/// let b = g.add_node("b");
+/// g.add_edge(a, b, 7);
+///
+/// let mut out = Vec::new();
+/// graphml::write(&g, &mut out).unwrap();
+/// let h: UnGraph<_, _> = graphml::read(&out[..]).unwrap();
+/// assert_eq!(h[b]["label"], "b");
+/// assert_eq!(h.raw_edges()[0].weight["label"], "7");
+/// ```
+pub fn write<N, E, Ty, Ix, W>(graph: &Graph<N, E, Ty, Ix>, writer: W) -> io::Result<()>
+where
+    N: Display,
+    E: Display,
+    Ty: EdgeType,
+    Ix: IndexType,
+    W: Write,
+{
+    let label = |x: String| {
+        let mut attrs = Attributes::new();
+        attrs.insert("label".to_string(), x);
+        attrs
+    };
+    write_with(
+        graph,
+        writer,
+        |n| label(n.to_string()),
+        |e| label(e.to_string()),
+    )
+}
+
+/// Write `graph` as GraphML, using `node_attrs` and `edge_attrs` to map the
+/// node data and edge weights to attributes.
+///
+/// Every attribute name that occurs is declared as a `<key>` of type string.
+/// Nodes get the ids `n0`, `n1`, ... in index order, edges `e0`, `e1`, ...
+pub fn write_with<N, E, Ty, Ix, W, FN, FE>(
+    graph: &Graph<N, E, Ty, Ix>,
+    mut writer: W,
+    mut node_attrs: FN,
+    mut edge_attrs: FE,
+) -> io::Result<()>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+    W: Write,
+    FN: FnMut(&N) -> Attributes,
+    FE: FnMut(&E) -> Attributes,
+{
+    let nodes: Vec<_> = graph
+        .raw_nodes()
+        .iter()
+        .map(|n| node_attrs(&n.data))
+        .collect();
+    let edges: Vec<_> = graph
+        .raw_edges()
+        .iter()
+        .map(|e| edge_attrs(&e.weight))
+        .collect();
+    let node_keys = key_names(&nodes);
+    let edge_keys = key_names(&edges);
+
+    writeln!(writer, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
+    writeln!(
+        writer,
+        "<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">"
+    )?;
+    for (kind, keys) in &[("node", &node_keys), ("edge", &edge_keys)] {
+        for (i, name) in keys.iter().enumerate() {
+            writeln!(
+                writer,
+                "  <key id=\"{}{}\" for=\"{}\" attr.name=\"{}\" attr.type=\"string\"/>",
+                &kind[..1],
+                i,
+                kind,
+                escape(name)
+            )?;
+        }
+    }
+    let edgedefault = if Ty::is_directed() {
+        "directed"
+    } else {
+        "undirected"
+    };
+    writeln!(writer, "  <graph id=\"G\" edgedefault=\"{}\">", edgedefault)?;
+    for (i, attrs) in nodes.iter().enumerate() {
+        write!(writer, "    <node id=\"n{}\"", i)?;
+        write_data(&mut writer, attrs, &node_keys, "n", "node")?;
+    }
+    for (i, (edge, attrs)) in graph.raw_edges().iter().zip(&edges).enumerate() {
+        write!(
+            writer,
+            "    <edge id=\"e{}\" source=\"n{}\" target=\"n{}\"",
+            i,
+            edge.source().index(),
+            edge.target().index()
+        )?;
+        write_data(&mut writer, attrs, &edge_keys, "e", "edge")?;
+    }
+    writeln!(writer, "  </graph>")?;
+    writeln!(writer, "</graphml>")
+}
+
+/// All attribute names in `attrs`, sorted.
+fn key_names(attrs: &[Attributes]) -> Vec<&str> {
+    let names: BTreeSet<_> = attrs.iter().flat_map(|a| a.keys()).collect();
+    names.into_iter().map(|s| &s[..]).collect()
+}
+
+/// Finish the start tag of a node or edge, write its `<data>` and close it.
+fn write_data<W: Write>(
+    writer: &mut W,
+    attrs: &Attributes,
+    keys: &[&str],
+    prefix: &str,
+    element: &str,
+) -> io::Result<()> {
+    if attrs.is_empty() {
+        return writeln!(writer, "/>");
+    }
+    writeln!(writer, ">")?;
+    for (i, name) in keys.iter().enumerate() {
+        if let Some(value) = attrs.get(*name) {
+            writeln!(
+                writer,
+                "      <data key=\"{}{}\">{}</data>",
+                prefix,
+                i,
+                escape(value)
+            )?;
+        }
+    }
+    writeln!(writer, "    </{}>", element)
+}
+
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Error returned by [`read`](fn.read.html).
+#[derive(Debug)]
+pub enum GraphMlError {
+    /// The underlying reader failed.
+    Io(io::Error),
+    /// The input isn't valid GraphML, or uses unsupported features.
+    Parse {
+        /// Line of the offending input, starting at 1.
+        line: usize,
+        /// What went wrong.
+        message: String,
+    },
+}
+
+impl Display for GraphMlError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            GraphMlError::Io(ref e) => write!(f, "i/o error: {}", e),
+            GraphMlError::Parse { line, ref message } => write!(f, "line {}: {}", line, message),
+        }
+    }
+}
+
+impl Error for GraphMlError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            GraphMlError::Io(ref e) => Some(e),
+            GraphMlError::Parse { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for GraphMlError {
+    fn from(e: io::Error) -> Self {
+        GraphMlError::Io(e)
+    }
+}
+
+/// Read a GraphML document.
+///
+/// The `<data>` of every node and edge is collected into its attribute map,
+/// keyed by the `attr.name` of the `<key>` (or the key id if it has no name).
+/// Key `<default>`s are filled in for nodes and edges that lack the attribute.
+/// Nodes are added in document order, their ids are not kept.
+///
+/// The `edgedefault` of the graph, and the `directed` attribute of every
+/// edge, must match `Ty`. Only the first `<graph>` is read; nested graphs,
+/// hyperedges and ports are not supported.
+///
+/// The whole document is read into memory before it is parsed.
+pub fn read<Ty, R>(mut reader: R) -> Result<Graph<Attributes, Attributes, Ty>, GraphMlError>
+where
+    Ty: EdgeType,
+    R: BufRead,
+{
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    GraphMlReader::default().read(&input)
+}
+
+#[derive(Default)]
+struct Key {
+    name: String,
+    domain: String,
+    default: Option<String>,
+}
+
+#[derive(Default)]
+struct GraphMlReader {
+    keys: HashMap<String, Key>,
+    nodes: Vec<Attributes>,
+    node_ids: HashMap<String, usize>,
+    edges: Vec<(String, String, Attributes, usize)>,
+}
+
+/// What the `<data>` currently being read belongs to.
+enum Owner {
+    Graph,
+    Node,
+    Edge,
+}
+
+impl GraphMlReader {
+    fn read<Ty: EdgeType>(
+        mut self,
+        input: &str,
+    ) -> Result<Graph<Attributes, Attributes, Ty>, GraphMlError> {
+        let mut xml = Xml::new(input);
+        let mut stack: Vec<String> = Vec::new();
+        let mut seen_graph = false;
+        let mut owner = None;
+        // the key of the current `<data>` or `<default>`, and its text
+        let mut data: Option<(String, String)> = None;
+        let mut current_key = None;
+
+        while let Some(event) = xml.next_event()? {
+            let line = xml.line;
+            let (name, attrs, empty) = match event {
+                XmlEvent::Text(text) => {
+                    if let Some((_, ref mut s)) = data {
+                        s.push_str(&text);
+                    }
+                    continue;
+                }
+                XmlEvent::End(name) => {
+                    match stack.pop() {
+                        Some(ref open) if *open == name => {}
+                        _ => return Err(parse_error(line, format!("unexpected `</{}>`", name))),
+                    }
+                    self.end(&name, &mut owner, &mut data, &mut current_key);
+                    continue;
+                }
+                XmlEvent::Start(name, attrs, empty) => (name, attrs, empty),
+            };
+            match &*name {
+                "key" => {
+                    let id = required(&attrs, "id", line)?;
+                    let key = Key {
+                        name: attrs.get("attr.name").unwrap_or(&id).clone(),
+                        domain: attrs.get("for").cloned().unwrap_or_default(),
+                        default: None,
+                    };
+                    self.keys.insert(id.clone(), key);
+                    current_key = Some(id);
+                }
+                "default" => {
+                    if let Some(ref id) = current_key {
+                        data = Some((id.clone(), String::new()));
+                    }
+                }
+                "graph" => {
+                    if seen_graph {
+                        return Err(parse_error(line, "only one graph is supported".into()));
+                    }
+                    seen_graph = true;
+                    check_directed::<Ty>(attrs.get("edgedefault"), "edgedefault", line)?;
+                    owner = Some(Owner::Graph);
+                }
+                "node" if owner.is_some() => {
+                    let id = required(&attrs, "id", line)?;
+                    if self.node_ids.insert(id.clone(), self.nodes.len()).is_some() {
+                        return Err(parse_error(line, format!("duplicate node id `{}`", id)));
+                    }
+                    self.nodes.push(Attributes::new());
+                    owner = Some(Owner::Node);
+                }
+                "edge" if owner.is_some() => {
+                    if let Some(directed) = attrs.get("directed") {
+                        let value = if directed == "true" {
+                            "directed"
+                        } else {
+                            "undirected"
+                        };
+                        check_directed::<Ty>(Some(&value.to_string()), "directed", line)?;
+                    }
+                    let source = required(&attrs, "source", line)?;
+                    let target = required(&attrs, "target", line)?;
+                    self.edges.push((source, target, Attributes::new(), line));
+                    owner = Some(Owner::Edge);
+                }
+                "data" => {
+                    data = Some((required(&attrs, "key", line)?, String::new()));
+                }
+                "hyperedge" | "port" => {
+                    return Err(parse_error(line, format!("`<{}>` is not supported", name)));
+                }
+                _ => {}
+            }
+            if empty {
+                self.end(&name, &mut owner, &mut data, &mut current_key);
+            } else {
+                stack.push(name);
+            }
+        }
+        if let Some(open) = stack.pop() {
+            return Err(parse_error(xml.line, format!("unclosed `<{}>`", open)));
+        }
+        if !seen_graph {
+            return Err(parse_error(xml.line, "no `<graph>` found".into()));
+        }
+        self.build()
+    }
+
+    /// Handle the end of the element `name`.
+    fn end(
+        &mut self,
+        name: &str,
+        owner: &mut Option<Owner>,
+        data: &mut Option<(String, String)>,
+        current_key: &mut Option<String>,
+    ) {
+        match name {
+            "key" => *current_key = None,
+            "default" => {
+                if let Some((id, text)) = data.take() {
+                    if let Some(key) = self.keys.get_mut(&id) {
+                        key.default = Some(text);
+                    }
+                }
+            }
+            "data" => {
+                let (id, text) = match data.take() {
+                    Some(data) => data,
+                    None => return,
+                };
+                let name = self.keys.get(&id).map_or(id, |key| key.name.clone());
+                let attrs = match *owner {
+                    Some(Owner::Node) => self.nodes.last_mut(),
+                    Some(Owner::Edge) => self.edges.last_mut().map(|e| &mut e.2),
+                    _ => None,
+                };
+                if let Some(attrs) = attrs {
+                    attrs.insert(name, text);
+                }
+            }
+            "node" | "edge" => *owner = Some(Owner::Graph),
+            "graph" => *owner = None,
+            _ => {}
+        }
+    }
+
+    fn build<Ty: EdgeType>(mut self) -> Result<Graph<Attributes, Attributes, Ty>, GraphMlError> {
+        for key in self.keys.values() {
+            if let Some(ref default) = key.default {
+                if key.domain == "node" || key.domain == "all" {
+                    for attrs in &mut self.nodes {
+                        let default = default.clone();
+                        attrs.entry(key.name.clone()).or_insert(default);
+                    }
+                }
+                if key.domain == "edge" || key.domain == "all" {
+                    for edge in &mut self.edges {
+                        let default = default.clone();
+                        edge.2.entry(key.name.clone()).or_insert(default);
+                    }
+                }
+            }
+        }
+
+        let mut g = Graph::with_capacity(self.nodes.len(), self.edges.len());
+        for attrs in self.nodes {
+            g.add_node(attrs);
+        }
+        let node_ids = self.node_ids;
+        for (source, target, attrs, line) in self.edges {
+            let index = |id: &str| match node_ids.get(id) {
+                Some(&i) => Ok(NodeIndex::new(i)),
+                None => Err(parse_error(line, format!("unknown node `{}`", id))),
+            };
+            g.add_edge(index(&source)?, index(&target)?, attrs);
+        }
+        Ok(g)
+    }
+}
+
+fn parse_error(line: usize, message: String) -> GraphMlError {
+    GraphMlError::Parse { line, message }
+}
+
+fn required(attrs: &Attributes, name: &str, line: usize) -> Result<String, GraphMlError> {
+    match attrs.get(name) {
+        Some(value) => Ok(value.clone()),
+        None => Err(parse_error(line, format!("missing attribute `{}`", name))),
+    }
+}
+
+fn check_directed<Ty: EdgeType>(
+    value: Option<&String>,
+    attr: &str,
+    line: usize,
+) -> Result<(), GraphMlError> {
+    let expected = if Ty::is_directed() {
+        "directed"
+    } else {
+        "undirected"
+    };
+    match value {
+        Some(value) if value != expected => Err(parse_error(
+            line,
+            format!("{} is `{}`, expected `{}`", attr, value, expected),
+        )),
+        _ => Ok(()),
+    }
+}
+
+enum XmlEvent {
+    /// Element name, attributes and whether it is empty (`<x/>`).
+    Start(String, Attributes, bool),
+    End(String),
+    Text(String),
+}
+
+/// Just enough of an XML tokenizer for GraphML.
+///
+/// Skips the prolog, comments, processing instructions and doctypes.
+/// Namespace prefixes of element names are dropped.
+struct Xml<'a> {
+    rest: &'a str,
+    line: usize,
+}
+
+impl<'a> Xml<'a> {
+    fn new(input: &'a str) -> Self {
+        Xml {
+            rest: input,
+            line: 1,
+        }
+    }
+
+    fn next_event(&mut self) -> Result<Option<XmlEvent>, GraphMlError> {
+        loop {
+            if self.rest.is_empty() {
+                return Ok(None);
+            }
+            if !self.rest.starts_with('<') {
+                let end = self.rest.find('<').unwrap_or(self.rest.len());
+                let text = self.advance(end);
+                return Ok(Some(XmlEvent::Text(unescape(text, self.line)?)));
+            }
+            if self.rest.starts_with("<![CDATA[") {
+                self.advance(9);
+                let text = self.advance_past("]]>")?;
+                return Ok(Some(XmlEvent::Text(text.to_string())));
+            }
+            if self.rest.starts_with("<!--") {
+                self.advance_past("-->")?;
+                continue;
+            }
+            if self.rest.starts_with("<?") {
+                self.advance_past("?>")?;
+                continue;
+            }
+            if self.rest.starts_with("<!") {
+                self.advance_past_tag()?;
+                continue;
+            }
+            let line = self.line;
+            self.advance(1);
+            let tag = self.advance_past_tag()?;
+            if let Some(name) = tag.strip_prefix('/') {
+                return Ok(Some(XmlEvent::End(local_name(name.trim()))));
+            }
+            let (tag, empty) = match tag.strip_suffix('/') {
+                Some(tag) => (tag, true),
+                None => (tag, false),
+            };
+            let name_end = tag.find(|c: char| c.is_whitespace()).unwrap_or(tag.len());
+            let attrs = attributes(&tag[name_end..], line)?;
+            return Ok(Some(XmlEvent::Start(
+                local_name(&tag[..name_end]),
+                attrs,
+                empty,
+            )));
+        }
+    }
+
+    fn advance(&mut self, len: usize) -> &'a str {
+        let (skipped, rest) = self.rest.split_at(len);
+        self.line += skipped.matches('\n').count();
+        self.rest = rest;
+        skipped
+    }
+
+    /// Skip past `end`, returning what came before it.
+    fn advance_past(&mut self, end: &str) -> Result<&'a str, GraphMlError> {
+        match self.rest.find(end) {
+            Some(i) => {
+                let skipped = self.advance(i);
+                self.advance(end.len());
+                Ok(skipped)
+            }
+            None => Err(parse_error(self.line, format!("missing `{}`", end))),
+        }
+    }
+
+    /// Skip past the `>` that ends a tag, returning what came before it.
+    /// A `>` inside a quoted attribute value doesn't end the tag.
+    fn advance_past_tag(&mut self) -> Result<&'a str, GraphMlError> {
+        let mut quote = None;
+        for (i, c) in self.rest.char_indices() {
+            match quote {
+                None if c == '>' => {
+                    let skipped = self.advance(i);
+                    self.advance(1);
+                    return Ok(skipped);
+                }
+                None if c == '"' || c == '\'' => quote = Some(c),
+                Some(q) if c == q => quote = None,
+                _ => {}
+            }
+        }
+        Err(parse_error(self.line, "missing `>`".to_string()))
+    }
+}
+
+fn local_name(name: &str) -> String {
+    name.rsplit(':').next().unwrap_or(name).to_string()
+}
+
+/// Parse the `name="value"` pairs of a start tag.
+fn attributes(mut s: &str, line: usize) -> Result<Attributes, GraphMlError> {
+    let mut attrs = Attributes::new();
+    loop {
+        s = s.trim_start();
+        if s.is_empty() {
+            return Ok(attrs);
+        }
+        let eq = match s.find('=') {
+            Some(eq) => eq,
+            None => return Err(parse_error(line, format!("malformed attribute `{}`", s))),
+        };
+        let name = s[..eq].trim().to_string();
+        s = s[eq + 1..].trim_start();
+        let quote = match s.chars().next() {
+            Some(q) if q == '"' || q == '\'' => q,
+            _ => return Err(parse_error(line, format!("unquoted value for `{}`", name))),
+        };
+        let end = match s[1..].find(quote) {
+            Some(end) => end + 1,
+            None => {
+                return Err(parse_error(
+                    line,
+                    format!("unterminated value for `{}`", name),
+                ))
+            }
+        };
+        attrs.insert(name, unescape(&s[1..end], line)?);
+        s = &s[end + 1..];
+    }
+}
+
+/// Replace the predefined and numeric character references in `s`.
+fn unescape(s: &str, line: usize) -> Result<String, GraphMlError> {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let semi = match rest.find(';') {
+            Some(semi) => semi,
+            None => return Err(parse_error(line, "unterminated entity".into())),
+        };
+        let entity = &rest[1..semi];
+        let c = match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ if entity.starts_with("#x") => u32::from_str_radix(&entity[2..], 16)
+                .ok()
+                .and_then(::std::char::from_u32),
+            _ if entity.starts_with('#') => {
+                entity[1..].parse().ok().and_then(::std::char::from_u32)
+            }
+            _ => None,
+        };
+        match c {
+            Some(c) => out.push(c),
+            None => return Err(parse_error(line, format!("unknown entity `&{};`", entity))),
+        }
+        rest = &rest[semi + 1..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
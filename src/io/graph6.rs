@@ -0,0 +1,349 @@
+//! The graph6 and sparse6 formats of nauty.
+//!
+//! Both encode an undirected graph as a single line of printable ASCII.
+//! graph6 packs the upper triangle of the adjacency matrix and suits dense
+//! graphs; sparse6 lists the edges and can also hold self-loops and parallel
+//! edges. Graph catalogs are usually distributed as files with one graph per
+//! line, which can be read with
+//! `BufRead::lines().map(|line| decode(&line?))`.
+//!
+//! See <https://users.cecs.anu.edu.au/~bdm/data/formats.txt> for the
+//! specification.
+use std::error::Error;
+use std::fmt;
+
+use graph::{DefaultIx, Graph, IndexType, NodeIndex, UnGraph};
+
+/// Largest number of nodes the formats can represent.
+const MAX_NODES: usize = (1 << 36) - 1;
+
+/// Encode `graph` in graph6.
+///
+/// Self-loops are dropped and parallel edges are encoded once, since graph6
+/// only stores whether two distinct nodes are adjacent. Node data and edge
+/// weights are ignored.
+///
+/// ```
+/// use graphs::*;
+/// use graphs::io::graph6;
+///
+/// // the path a - b - c
+/// let mut g = UnGraph::<(), ()>::new_undirected();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// g.add_edge(a, b, ());
+/// g.add_edge(b, c, ());
+/// assert_eq!(graph6::encode(&g), "Bg");
+/// ```
+///
+/// **Panics** if the graph has more than 2<sup>36</sup> - 1 nodes.
+pub fn encode<N, E, Ix: IndexType>(graph: &UnGraph<N, E, Ix>) -> String {
+    let n = graph.node_count();
+    let mut bits = vec![false; n * n.saturating_sub(1) / 2];
+    for edge in graph.raw_edges() {
+        let (a, b) = (edge.source().index(), edge.target().index());
+        if a != b {
+            let (i, j) = if a < b { (a, b) } else { (b, a) };
+            bits[j * (j - 1) / 2 + i] = true;
+        }
+    }
+    let mut out = BitWriter::new(size_prefix(n));
+    for bit in bits {
+        out.push(bit);
+    }
+    out.finish(false)
+}
+
+/// Encode `graph` in sparse6.
+///
+/// Self-loops and parallel edges are kept. Edges are written sorted by their
+/// larger endpoint, so decoding may yield them in a different order. Node
+/// data and edge weights are ignored.
+///
+/// **Panics** if the graph has more than 2<sup>36</sup> - 1 nodes.
+pub fn encode_sparse6<N, E, Ix: IndexType>(graph: &UnGraph<N, E, Ix>) -> String {
+    let n = graph.node_count();
+    let k = bit_length(n.saturating_sub(1));
+    let mut edges: Vec<_> = graph
+        .raw_edges()
+        .iter()
+        .map(|e| {
+            let (a, b) = (e.source().index(), e.target().index());
+            if a <= b {
+                (b, a)
+            } else {
+                (a, b)
+            }
+        })
+        .collect();
+    edges.sort();
+
+    let mut out = BitWriter::new(":".to_string() + &size_prefix(n));
+    let mut current = 0;
+    for (v, u) in edges {
+        if v == current {
+            out.push(false);
+        } else {
+            out.push(true);
+            if v > current + 1 {
+                out.push_number(v, k);
+                out.push(false);
+            }
+            current = v;
+        }
+        out.push_number(u, k);
+    }
+    // Padding with ones could be read as a self-loop at n - 1 if the last
+    // edge ends at n - 2 and k + 1 padding bits fit.
+    let padding = (6 - out.len % 6) % 6;
+    if k < 6 && n == 1 << k && current + 2 == n && padding > k {
+        out.push(false);
+    }
+    out.finish(true)
+}
+
+/// Decode a graph in graph6 or sparse6, detected by the leading `:` of
+/// sparse6.
+///
+/// An optional `>>graph6<<` or `>>sparse6<<` header and trailing whitespace
+/// are ignored. Node indices follow the node order of the encoding. The
+/// incremental sparse6 and the digraph6 variants are not supported.
+///
+/// The input is validated before the nodes are allocated. Note that sparse6
+/// needs no data for isolated nodes, so a few bytes of untrusted input can
+/// still describe a graph with billions of nodes.
+pub fn decode(s: &str) -> Result<UnGraph<(), ()>, Graph6Error> {
+    let s = s.trim_end();
+    let s = s
+        .strip_prefix(">>graph6<<")
+        .or_else(|| s.strip_prefix(">>sparse6<<"))
+        .unwrap_or(s);
+    let bytes = s.as_bytes();
+    for (position, &byte) in bytes.iter().enumerate() {
+        let leading = position == 0 && (byte == b':' || byte == b';' || byte == b'&');
+        if !leading && !(63..=126).contains(&byte) {
+            return Err(Graph6Error::InvalidByte { position, byte });
+        }
+    }
+    match bytes.first() {
+        Some(b';') => Err(Graph6Error::Unsupported("incremental sparse6")),
+        Some(b'&') => Err(Graph6Error::Unsupported("digraph6")),
+        Some(b':') => decode_sparse6(&bytes[1..]),
+        _ => decode_graph6(bytes),
+    }
+}
+
+fn decode_graph6(bytes: &[u8]) -> Result<UnGraph<(), ()>, Graph6Error> {
+    let (n, rest) = parse_size(bytes)?;
+    check_size(n)?;
+    let bit_count = n
+        .checked_mul(n.saturating_sub(1))
+        .ok_or(Graph6Error::TooLarge(n))?
+        / 2;
+    let expected = bit_count.div_ceil(6);
+    if rest.len() < expected {
+        return Err(Graph6Error::UnexpectedEnd);
+    }
+    if rest.len() > expected {
+        return Err(Graph6Error::TrailingData);
+    }
+    let mut g = empty_graph(n, 0);
+    let mut bits = BitReader::new(rest);
+    for j in 1..n {
+        for i in 0..j {
+            if bits.read(1) == Some(1) {
+                g.add_edge(NodeIndex::new(i), NodeIndex::new(j), ());
+            }
+        }
+    }
+    Ok(g)
+}
+
+fn decode_sparse6(bytes: &[u8]) -> Result<UnGraph<(), ()>, Graph6Error> {
+    let (n, rest) = parse_size(bytes)?;
+    check_size(n)?;
+    let k = bit_length(n.saturating_sub(1));
+    // read all edges before allocating the nodes, the header alone can ask
+    // for billions of them
+    let mut edges = Vec::new();
+    let mut bits = BitReader::new(rest);
+    let mut v = 0;
+    while let (Some(b), Some(x)) = (bits.read(1), bits.read(k)) {
+        if b == 1 {
+            v += 1;
+        }
+        if v >= n {
+            break;
+        }
+        if x > v {
+            v = x;
+        } else {
+            edges.push((x, v));
+        }
+    }
+    let mut g = empty_graph(n, edges.len());
+    for (a, b) in edges {
+        g.add_edge(NodeIndex::new(a), NodeIndex::new(b), ());
+    }
+    Ok(g)
+}
+
+fn check_size(n: usize) -> Result<(), Graph6Error> {
+    if n > <DefaultIx as IndexType>::max().index() {
+        Err(Graph6Error::TooLarge(n))
+    } else {
+        Ok(())
+    }
+}
+
+fn empty_graph(n: usize, edges: usize) -> UnGraph<(), ()> {
+    let mut g = Graph::with_capacity(n, edges);
+    for _ in 0..n {
+        g.add_node(());
+    }
+    g
+}
+
+/// The number of nodes, encoded in one, four or eight bytes.
+fn size_prefix(n: usize) -> String {
+    assert!(n <= MAX_NODES, "graph too large for graph6 or sparse6");
+    let digits = if n < 63 {
+        return ((n + 63) as u8 as char).to_string();
+    } else if n < 258_048 {
+        // larger numbers would start with `~` and look like the long form
+        3
+    } else {
+        6
+    };
+    let mut s = "~".repeat(digits / 3);
+    for i in (0..digits).rev() {
+        s.push((((n >> (6 * i)) & 63) as u8 + 63) as char);
+    }
+    s
+}
+
+fn parse_size(bytes: &[u8]) -> Result<(usize, &[u8]), Graph6Error> {
+    let value = |digits: &[u8]| {
+        digits
+            .iter()
+            .fold(0, |n, &byte| (n << 6) | (byte - 63) as usize)
+    };
+    match bytes {
+        [] => Err(Graph6Error::UnexpectedEnd),
+        [126, 126, rest @ ..] if rest.len() >= 6 => Ok((value(&rest[..6]), &rest[6..])),
+        [126, 126, ..] => Err(Graph6Error::UnexpectedEnd),
+        [126, rest @ ..] if rest.len() >= 3 => Ok((value(&rest[..3]), &rest[3..])),
+        [126, ..] => Err(Graph6Error::UnexpectedEnd),
+        [first, rest @ ..] => Ok(((first - 63) as usize, rest)),
+    }
+}
+
+/// Number of bits needed to write `x` in binary.
+fn bit_length(x: usize) -> usize {
+    (0usize.leading_zeros() - x.leading_zeros()) as usize
+}
+
+/// Packs bits into printable bytes, six per byte.
+struct BitWriter {
+    out: String,
+    byte: u8,
+    len: usize,
+}
+
+impl BitWriter {
+    fn new(prefix: String) -> Self {
+        BitWriter {
+            out: prefix,
+            byte: 0,
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, bit: bool) {
+        self.byte = (self.byte << 1) | bit as u8;
+        self.len += 1;
+        if self.len.is_multiple_of(6) {
+            self.out.push((self.byte + 63) as char);
+            self.byte = 0;
+        }
+    }
+
+    /// Push the lowest `k` bits of `x`, most significant first.
+    fn push_number(&mut self, x: usize, k: usize) {
+        for i in (0..k).rev() {
+            self.push((x >> i) & 1 == 1);
+        }
+    }
+
+    /// Pad the last byte with `padding` bits and return the result.
+    fn finish(mut self, padding: bool) -> String {
+        while !self.len.is_multiple_of(6) {
+            self.push(padding);
+        }
+        self.out
+    }
+}
+
+/// Reads bits from printable bytes, six per byte.
+struct BitReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        BitReader { bytes, position: 0 }
+    }
+
+    /// Read a `k` bit number, or `None` if fewer bits are left.
+    fn read(&mut self, k: usize) -> Option<usize> {
+        if self.position + k > self.bytes.len() * 6 {
+            return None;
+        }
+        let mut x = 0;
+        for _ in 0..k {
+            let byte = self.bytes[self.position / 6] - 63;
+            let bit = (byte >> (5 - self.position % 6)) & 1;
+            x = (x << 1) | bit as usize;
+            self.position += 1;
+        }
+        Some(x)
+    }
+}
+
+/// Error returned by [`decode`](fn.decode.html).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Graph6Error {
+    /// A byte outside of the printable range `63..=126`.
+    InvalidByte {
+        /// Offset of the byte, after any header.
+        position: usize,
+        /// The byte itself.
+        byte: u8,
+    },
+    /// The input ended before the whole graph was read.
+    UnexpectedEnd,
+    /// There is more input after the graph.
+    TrailingData,
+    /// The graph has more nodes than the default index type can hold.
+    TooLarge(usize),
+    /// A variant of the format that is not supported.
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for Graph6Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Graph6Error::InvalidByte { position, byte } => {
+                write!(f, "invalid byte {:#04x} at offset {}", byte, position)
+            }
+            Graph6Error::UnexpectedEnd => write!(f, "unexpected end of input"),
+            Graph6Error::TrailingData => write!(f, "unexpected data after the graph"),
+            Graph6Error::TooLarge(n) => write!(f, "too many nodes: {}", n),
+            Graph6Error::Unsupported(variant) => write!(f, "{} is not supported", variant),
+        }
+    }
+}
+
+impl Error for Graph6Error {}
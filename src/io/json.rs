@@ -0,0 +1,215 @@
+//! The d3-style JSON node-link format, enabled by the `json` feature.
+//!
+//! A graph is represented as
+//!
+//! ```json
+//! {
+//!   "directed": true,
+//!   "nodes": [{ "id": 0, "data": ... }, ...],
+//!   "links": [{ "source": 0, "target": 1, "weight": ... }, ...]
+//! }
+//! ```
+//!
+//! where `source` and `target` refer to the `id`s of the nodes.
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{self, Map, Value};
+
+use graph::{EdgeType, Graph, IndexType, NodeIndex};
+
+/// Convert `graph` to the node-link format, using the node indices as ids.
+///
+/// ```
+/// use graphs::*;
+/// use graphs::io::json::to_node_link;
+///
+/// let mut g = DiGraph::<&str, u32>::new();
+/// let a = g.add_node("a");
+/// let b = g.add_node("b");
+/// g.add_edge(a, b, 7);
+///
+/// assert_eq!(
+///     to_node_link(&g).unwrap().to_string(),
+///     r#"{"directed":true,"links":[{"source":0,"target":1,"weight":7}],"nodes":[{"data":"a","id":0},{"data":"b","id":1}]}"#
+/// );
+/// ```
+///
+/// Fails if node data or an edge weight can't be represented in JSON, like
+/// a map with non-string keys.
+pub fn to_node_link<N, E, Ty, Ix>(graph: &Graph<N, E, Ty, Ix>) -> Result<Value, serde_json::Error>
+where
+    N: Serialize,
+    E: Serialize,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    to_node_link_with(graph, |i, _| Value::from(i.index()))
+}
+
+/// Convert `graph` to the node-link format, using `id_field` to compute the
+/// id of every node, e.g. to emit string ids taken from the node data.
+///
+/// The ids should be unique, or the result can't be read back. Fails like
+/// [`to_node_link`](fn.to_node_link.html).
+pub fn to_node_link_with<N, E, Ty, Ix, F>(
+    graph: &Graph<N, E, Ty, Ix>,
+    mut id_field: F,
+) -> Result<Value, serde_json::Error>
+where
+    N: Serialize,
+    E: Serialize,
+    Ty: EdgeType,
+    Ix: IndexType,
+    F: FnMut(NodeIndex<Ix>, &N) -> Value,
+{
+    let ids: Vec<_> = graph
+        .raw_nodes()
+        .iter()
+        .enumerate()
+        .map(|(i, n)| id_field(NodeIndex::new(i), &n.data))
+        .collect();
+    let nodes = graph
+        .raw_nodes()
+        .iter()
+        .zip(&ids)
+        .map(|(n, id)| {
+            let mut node = Map::new();
+            node.insert("id".to_string(), id.clone());
+            node.insert("data".to_string(), serde_json::to_value(&n.data)?);
+            Ok(Value::Object(node))
+        })
+        .collect::<Result<_, serde_json::Error>>()?;
+    let links = graph
+        .raw_edges()
+        .iter()
+        .map(|e| {
+            let mut link = Map::new();
+            link.insert("source".to_string(), ids[e.source().index()].clone());
+            link.insert("target".to_string(), ids[e.target().index()].clone());
+            link.insert("weight".to_string(), serde_json::to_value(&e.weight)?);
+            Ok(Value::Object(link))
+        })
+        .collect::<Result<_, serde_json::Error>>()?;
+
+    let mut object = Map::new();
+    object.insert("directed".to_string(), Value::Bool(Ty::is_directed()));
+    object.insert("nodes".to_string(), Value::Array(nodes));
+    object.insert("links".to_string(), Value::Array(links));
+    Ok(Value::Object(object))
+}
+
+/// Error returned by [`from_node_link`](fn.from_node_link.html).
+#[derive(Debug)]
+pub enum NodeLinkError {
+    /// Node data or an edge weight could not be deserialized.
+    Json(serde_json::Error),
+    /// The value doesn't follow the node-link format.
+    Format(String),
+}
+
+impl fmt::Display for NodeLinkError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            NodeLinkError::Json(ref e) => write!(f, "invalid data: {}", e),
+            NodeLinkError::Format(ref message) => write!(f, "invalid node-link graph: {}", message),
+        }
+    }
+}
+
+impl Error for NodeLinkError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            NodeLinkError::Json(ref e) => Some(e),
+            NodeLinkError::Format(_) => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for NodeLinkError {
+    fn from(e: serde_json::Error) -> Self {
+        NodeLinkError::Json(e)
+    }
+}
+
+/// Build a graph from a value in the node-link format.
+///
+/// Nodes are added in the order they are listed, their ids can be any JSON
+/// value. A missing `data` or `weight` is read as `null`. If `directed` is
+/// present it must match `Ty`.
+pub fn from_node_link<N, E, Ty, Ix>(value: Value) -> Result<Graph<N, E, Ty, Ix>, NodeLinkError>
+where
+    N: DeserializeOwned,
+    E: DeserializeOwned,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    let invalid = |message: &str| NodeLinkError::Format(message.to_string());
+    let mut object = match value {
+        Value::Object(object) => object,
+        _ => return Err(invalid("expected an object")),
+    };
+    match object.get("directed") {
+        None => {}
+        Some(&Value::Bool(directed)) if directed == Ty::is_directed() => {}
+        Some(_) => return Err(invalid("`directed` doesn't match the edge type")),
+    }
+    let nodes = array(&mut object, "nodes")?;
+    let links = array(&mut object, "links")?;
+    if nodes.len() > <Ix as IndexType>::max().index()
+        || links.len() > <Ix as IndexType>::max().index()
+    {
+        return Err(invalid("graph too large for its index type"));
+    }
+
+    let mut g = Graph::with_capacity(nodes.len(), links.len());
+    let mut ids = HashMap::new();
+    for node in nodes {
+        let mut node = match node {
+            Value::Object(node) => node,
+            _ => return Err(invalid("expected every node to be an object")),
+        };
+        let id = match node.remove("id") {
+            Some(id) => id.to_string(),
+            None => return Err(invalid("node without `id`")),
+        };
+        let data = serde_json::from_value(node.remove("data").unwrap_or(Value::Null))?;
+        if ids.insert(id.clone(), g.add_node(data)).is_some() {
+            return Err(NodeLinkError::Format(format!("duplicate node id {}", id)));
+        }
+    }
+    for link in links {
+        let mut link = match link {
+            Value::Object(link) => link,
+            _ => return Err(invalid("expected every link to be an object")),
+        };
+        let endpoint = |field: &str| -> Result<NodeIndex<Ix>, NodeLinkError> {
+            let id = match link.get(field) {
+                Some(id) => id.to_string(),
+                None => return Err(NodeLinkError::Format(format!("link without `{}`", field))),
+            };
+            match ids.get(&id) {
+                Some(&ix) => Ok(ix),
+                None => Err(NodeLinkError::Format(format!("unknown node id {}", id))),
+            }
+        };
+        let (a, b) = (endpoint("source")?, endpoint("target")?);
+        let weight = serde_json::from_value(link.remove("weight").unwrap_or(Value::Null))?;
+        g.add_edge(a, b, weight);
+    }
+    Ok(g)
+}
+
+fn array(object: &mut Map<String, Value>, field: &str) -> Result<Vec<Value>, NodeLinkError> {
+    match object.remove(field) {
+        Some(Value::Array(values)) => Ok(values),
+        Some(_) => Err(NodeLinkError::Format(format!(
+            "`{}` is not an array",
+            field
+        ))),
+        None => Err(NodeLinkError::Format(format!("missing `{}`", field))),
+    }
+}
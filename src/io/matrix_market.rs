@@ -0,0 +1,160 @@
+//! Reader for the coordinate format of Matrix Market `.mtx` files.
+//!
+//! ```text
+//! %%MatrixMarket matrix coordinate real symmetric
+//! % comment
+//! 3 3 2
+//! 2 1 0.5
+//! 3 2 1.5
+//! ```
+//!
+//! The size line gives the number of rows, columns and entries. Every entry
+//! `i j w` becomes an edge from node `i - 1` to node `j - 1` with weight `w`.
+use std::io::BufRead;
+
+use graph::{DefaultIx, DiGraph, EdgeType, Graph, IndexType, NodeIndex, UnGraph};
+use io::ReadError;
+
+/// A graph read from a Matrix Market file, directed or undirected depending
+/// on the symmetry given in its header.
+pub enum MatrixMarketGraph {
+    /// Read from a `general` matrix.
+    Directed(DiGraph<(), f64>),
+    /// Read from a `symmetric` matrix, that only lists one triangle.
+    Undirected(UnGraph<(), f64>),
+}
+
+/// Read a coordinate format Matrix Market file, line by line.
+///
+/// The graph has a node for every row or column, whichever are more, and an
+/// edge for every entry. `real` and `integer` entries are read as weights,
+/// `pattern` entries get the weight `1.0`. `general` matrices produce a
+/// directed graph, `symmetric` ones an undirected graph.
+///
+/// The `array` format, `complex` values and the `skew-symmetric` and
+/// `hermitian` symmetries are not supported.
+///
+/// All entries are read and checked before the nodes are allocated, so a
+/// truncated or malformed file with a huge size fails quickly.
+/// A well-formed file still gets all the nodes it declares, so limit the
+/// size of untrusted input.
+pub fn read<R: BufRead>(reader: R) -> Result<MatrixMarketGraph, ReadError> {
+    let mut lines = reader.lines().enumerate();
+    let header = match lines.next() {
+        Some((_, line)) => line?,
+        None => return Err(ReadError::parse(1, "missing header")),
+    };
+    let words: Vec<_> = header
+        .split_whitespace()
+        .map(|w| w.to_lowercase())
+        .collect();
+    if words.len() != 5 || words[0] != "%%matrixmarket" || words[1] != "matrix" {
+        return Err(ReadError::parse(
+            1,
+            "expected `%%MatrixMarket matrix <format> <field> <symmetry>`",
+        ));
+    }
+    if words[2] != "coordinate" {
+        return Err(ReadError::parse(
+            1,
+            format!("unsupported format {}", words[2]),
+        ));
+    }
+    let pattern = match &*words[3] {
+        "real" | "integer" => false,
+        "pattern" => true,
+        field => return Err(ReadError::parse(1, format!("unsupported field {}", field))),
+    };
+    match &*words[4] {
+        "general" => read_entries(lines, pattern).map(MatrixMarketGraph::Directed),
+        "symmetric" => read_entries(lines, pattern).map(MatrixMarketGraph::Undirected),
+        symmetry => Err(ReadError::parse(
+            1,
+            format!("unsupported symmetry {}", symmetry),
+        )),
+    }
+}
+
+fn read_entries<Ty, I>(lines: I, pattern: bool) -> Result<Graph<(), f64, Ty>, ReadError>
+where
+    Ty: EdgeType,
+    I: Iterator<Item = (usize, ::std::io::Result<String>)>,
+{
+    let mut size = None;
+    let mut edges = Vec::new();
+    let mut line_number = 1;
+    for (i, line) in lines {
+        line_number = i + 1;
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('%') {
+            continue;
+        }
+        let words: Vec<_> = line.split_whitespace().collect();
+        let number = |i: usize| -> Result<usize, ReadError> {
+            match words.get(i).map(|w| w.parse()) {
+                Some(Ok(x)) => Ok(x),
+                _ => Err(ReadError::parse(
+                    line_number,
+                    format!("invalid line `{}`", line),
+                )),
+            }
+        };
+        let (rows, columns, nonzeros) = match size {
+            Some(size) => size,
+            None => {
+                let new_size = (number(0)?, number(1)?, number(2)?);
+                if new_size.0.max(new_size.1) > <DefaultIx as IndexType>::max().index() {
+                    return Err(ReadError::parse(line_number, "too many rows or columns"));
+                }
+                size = Some(new_size);
+                continue;
+            }
+        };
+        if edges.len() == nonzeros {
+            return Err(ReadError::parse(
+                line_number,
+                format!("more than {} entries", nonzeros),
+            ));
+        }
+        let (row, column) = (number(0)?, number(1)?);
+        if row == 0 || row > rows || column == 0 || column > columns {
+            return Err(ReadError::parse(
+                line_number,
+                format!("entry ({}, {}) out of bounds", row, column),
+            ));
+        }
+        let weight = if pattern {
+            1.0
+        } else {
+            match words.get(2).map(|w| w.parse()) {
+                Some(Ok(w)) => w,
+                _ => {
+                    return Err(ReadError::parse(
+                        line_number,
+                        format!("invalid line `{}`", line),
+                    ))
+                }
+            }
+        };
+        edges.push((NodeIndex::new(row - 1), NodeIndex::new(column - 1), weight));
+    }
+    match size {
+        None => Err(ReadError::parse(line_number, "missing size line")),
+        Some((_, _, nonzeros)) if edges.len() < nonzeros => Err(ReadError::parse(
+            line_number,
+            format!("expected {} entries, found {}", nonzeros, edges.len()),
+        )),
+        Some((rows, columns, _)) => {
+            let n = rows.max(columns);
+            let mut g = Graph::with_capacity(n, edges.len());
+            for _ in 0..n {
+                g.add_node(());
+            }
+            for (a, b, weight) in edges {
+                g.add_edge(a, b, weight);
+            }
+            Ok(g)
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! Reading and writing graphs in further file formats.
+//!
+//! See also the [`dot`](../dot/index.html) and
+//! [`graphml`](../graphml/index.html) modules.
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+pub mod graph6;
+#[cfg(feature = "json")]
+pub mod json;
+pub mod matrix_market;
+pub mod pajek;
+
+/// Error returned by the line based readers of this module.
+#[derive(Debug)]
+pub enum ReadError {
+    /// The underlying reader failed.
+    Io(io::Error),
+    /// The input is malformed or uses unsupported features.
+    Parse {
+        /// Line of the offending input, starting at 1.
+        line: usize,
+        /// What went wrong.
+        message: String,
+    },
+}
+
+impl ReadError {
+    fn parse<S: Into<String>>(line: usize, message: S) -> Self {
+        ReadError::Parse {
+            line,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ReadError::Io(ref e) => write!(f, "i/o error: {}", e),
+            ReadError::Parse { line, ref message } => write!(f, "line {}: {}", line, message),
+        }
+    }
+}
+
+impl Error for ReadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ReadError::Io(ref e) => Some(e),
+            ReadError::Parse { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for ReadError {
+    fn from(e: io::Error) -> Self {
+        ReadError::Io(e)
+    }
+}
@@ -0,0 +1,212 @@
+//! The Pajek `.net` format.
+//!
+//! ```text
+//! *Vertices 3
+//! 1 "a"
+//! 2 "b"
+//! 3 "c"
+//! *Arcs
+//! 1 2 1.5
+//! 2 3 1
+//! ```
+//!
+//! Vertices are numbered from 1. Directed graphs list their edges in an
+//! `*Arcs` section, undirected graphs in an `*Edges` section.
+use std::fmt::Display;
+use std::io::{self, BufRead, Write};
+
+use graph::{DefaultIx, EdgeType, Graph, IndexType, NodeIndex};
+use io::ReadError;
+
+/// Write `graph` in the Pajek format, using the `Display` implementations of
+/// the node data and edge weights as vertex labels and edge weights.
+///
+/// Labels are written in double quotes. Pajek has no way to escape a `"` or
+/// a line break in a label, or whitespace in a weight, so these fail with an
+/// error of kind `InvalidData`; nothing is written for the offending line.
+pub fn write<N, E, Ty, Ix, W>(graph: &Graph<N, E, Ty, Ix>, mut writer: W) -> io::Result<()>
+where
+    N: Display,
+    E: Display,
+    Ty: EdgeType,
+    Ix: IndexType,
+    W: Write,
+{
+    writeln!(writer, "*Vertices {}", graph.node_count())?;
+    for (i, node) in graph.raw_nodes().iter().enumerate() {
+        let label = node.data.to_string();
+        if label.contains(&['"', '\n', '\r'][..]) {
+            return Err(invalid_data(format!(
+                "vertex {} has a label with a quote or line break",
+                i + 1
+            )));
+        }
+        writeln!(writer, "{} \"{}\"", i + 1, label)?;
+    }
+    let section = if Ty::is_directed() { "Arcs" } else { "Edges" };
+    writeln!(writer, "*{}", section)?;
+    for edge in graph.raw_edges() {
+        let weight = edge.weight.to_string();
+        if weight.is_empty() || weight.contains(char::is_whitespace) {
+            return Err(invalid_data(format!(
+                "edge {} -> {} has an empty weight or one with whitespace",
+                edge.source().index() + 1,
+                edge.target().index() + 1
+            )));
+        }
+        writeln!(
+            writer,
+            "{} {} {}",
+            edge.source().index() + 1,
+            edge.target().index() + 1,
+            weight
+        )?;
+    }
+    Ok(())
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Section {
+    None,
+    Vertices,
+    Edges,
+}
+
+/// Read a graph in the Pajek format, line by line.
+///
+/// Every vertex becomes a node with its label as data, or its number if it
+/// has no label. Edges without a weight get the weight `1.0`. Vertex
+/// coordinates and other trailing values are ignored, as are lines starting
+/// with `%`.
+///
+/// A directed graph must only have `*Arcs`, an undirected one only
+/// `*Edges`. The list and matrix sections are not supported.
+///
+/// The whole input is read and checked before the nodes are allocated, so a
+/// truncated or malformed file with a huge vertex count fails quickly.
+/// A well-formed file still gets all the nodes it declares, so limit the
+/// size of untrusted input.
+pub fn read<Ty, R>(reader: R) -> Result<Graph<String, f64, Ty>, ReadError>
+where
+    Ty: EdgeType,
+    R: BufRead,
+{
+    let mut vertex_count = 0;
+    let mut labels = Vec::new();
+    let mut edges = Vec::new();
+    let mut section = Section::None;
+    let mut seen_vertices = false;
+    for (i, line) in reader.lines().enumerate() {
+        let line_number = i + 1;
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('%') {
+            continue;
+        }
+        let error = |message: String| ReadError::parse(line_number, message);
+        if line.starts_with('*') {
+            let mut words = line.split_whitespace();
+            let keyword = words.next().unwrap_or("").to_lowercase();
+            section = match &*keyword {
+                "*network" => Section::None,
+                "*vertices" => {
+                    if seen_vertices {
+                        return Err(error("duplicate *Vertices section".to_string()));
+                    }
+                    seen_vertices = true;
+                    vertex_count = match words.next().map(str::parse) {
+                        Some(Ok(n)) => n,
+                        _ => return Err(error("expected the number of vertices".to_string())),
+                    };
+                    if vertex_count > <DefaultIx as IndexType>::max().index() {
+                        return Err(error(format!("too many vertices: {}", vertex_count)));
+                    }
+                    Section::Vertices
+                }
+                "*arcs" | "*edges" => {
+                    let directed = keyword == "*arcs";
+                    if directed != Ty::is_directed() {
+                        return Err(error(format!(
+                            "{} section in a graph read as {}",
+                            &line[..keyword.len()],
+                            if Ty::is_directed() {
+                                "directed"
+                            } else {
+                                "undirected"
+                            }
+                        )));
+                    }
+                    Section::Edges
+                }
+                _ => return Err(error(format!("unsupported section {}", line))),
+            };
+            continue;
+        }
+
+        let vertex = |s: Option<&str>| -> Result<NodeIndex, ReadError> {
+            let s = s.unwrap_or("");
+            match s.parse::<usize>() {
+                Ok(v) if v >= 1 && v <= vertex_count => Ok(NodeIndex::new(v - 1)),
+                _ => Err(ReadError::parse(
+                    line_number,
+                    format!("invalid vertex `{}`", s),
+                )),
+            }
+        };
+        match section {
+            Section::None => return Err(error(format!("unexpected line `{}`", line))),
+            Section::Vertices => {
+                let (id, rest) = split_first(line);
+                let v = vertex(Some(id))?;
+                if let Some(label) = label(rest) {
+                    labels.push((v, label.to_string()));
+                }
+            }
+            Section::Edges => {
+                let mut words = line.split_whitespace();
+                let a = vertex(words.next())?;
+                let b = vertex(words.next())?;
+                let weight = match words.next() {
+                    None => 1.0,
+                    Some(w) => match w.parse() {
+                        Ok(w) => w,
+                        Err(_) => return Err(error(format!("invalid weight `{}`", w))),
+                    },
+                };
+                edges.push((a, b, weight));
+            }
+        }
+    }
+    let mut g = Graph::with_capacity(vertex_count, edges.len());
+    for v in 1..=vertex_count {
+        g.add_node(v.to_string());
+    }
+    for (v, label) in labels {
+        g[v] = label;
+    }
+    for (a, b, weight) in edges {
+        g.add_edge(a, b, weight);
+    }
+    Ok(g)
+}
+
+/// Split `s` at its first whitespace.
+fn split_first(s: &str) -> (&str, &str) {
+    match s.find(char::is_whitespace) {
+        Some(i) => (&s[..i], s[i..].trim_start()),
+        None => (s, ""),
+    }
+}
+
+/// The label at the start of `s`, quoted or a single word.
+fn label(s: &str) -> Option<&str> {
+    if let Some(quoted) = s.strip_prefix('"') {
+        Some(quoted.find('"').map_or(quoted, |end| &quoted[..end]))
+    } else {
+        Some(split_first(s).0).filter(|l| !l.is_empty())
+    }
+}
@@ -11,9 +11,34 @@
 //! at **petgraph** insted.
 #![deny(missing_docs)]
 
+#[cfg(feature = "bin")]
+extern crate bincode;
+#[cfg(feature = "quickcheck")]
+extern crate quickcheck;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(any(test, feature = "json"))]
+extern crate serde_json;
+
+pub mod algo;
+#[cfg(feature = "quickcheck")]
+mod arbitrary;
+#[cfg(feature = "bin")]
+pub mod binary;
+pub mod dot;
+pub mod generators;
 mod graph;
+pub mod graphml;
+pub mod io;
+pub mod operator;
+#[cfg(feature = "serde")]
+mod serialization;
+pub mod stable_graph;
 
 // #[cfg(test)]
 mod tests;
 
 pub use self::graph::*;
+pub use self::stable_graph::{StableDiGraph, StableGraph, StableUnGraph};
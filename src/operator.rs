@@ -0,0 +1,139 @@
+//! Operators that combine graphs into new graphs.
+use graph::{EdgeType, Graph, IndexType, NodeIndex};
+
+/// Copy the graphs `a` and `b` into a new graph containing both, side by side.
+///
+/// The nodes and edges of `a` keep their indices, the nodes of `b` are shifted
+/// by `a.node_count()` and its edges by `a.edge_count()`.
+///
+/// Return the new graph and the offset applied to the node indices of `b`.
+///
+/// Use [`Graph::append`](../struct.Graph.html#method.append) to move a graph
+/// into an existing one without cloning.
+pub fn disjoint_union<N, E, Ty, Ix>(
+    a: &Graph<N, E, Ty, Ix>,
+    b: &Graph<N, E, Ty, Ix>,
+) -> (Graph<N, E, Ty, Ix>, usize)
+where
+    N: Clone,
+    E: Clone,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    let mut g = a.clone();
+    let offset = g.append(b.clone()).start;
+    (g, offset)
+}
+
+/// The edge weight of a product graph, telling which factor the edge came from.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ProductEdge<E1, E2> {
+    /// Edge of the first factor, the second coordinate stays fixed.
+    First(E1),
+    /// Edge of the second factor, the first coordinate stays fixed.
+    Second(E2),
+    /// Pair of edges, one from each factor.
+    Both(E1, E2),
+}
+
+/// Return the cartesian product `g1 □ g2`.
+///
+/// Every pair of nodes `(u, v)` becomes a node with the cloned data `(u, v)`.
+/// `(u1, v1)` and `(u2, v2)` are connected if `u1 == u2` and `v1 → v2` is an
+/// edge of `g2` (`ProductEdge::Second`), or if `v1 == v2` and `u1 → u2` is an
+/// edge of `g1` (`ProductEdge::First`).
+///
+/// The node indices are laid out row-major, `g1` first: the node for
+/// `(u, v)` has index `u.index() * g2.node_count() + v.index()`.
+///
+/// The product of two paths is a grid graph.
+pub fn cartesian_product<N1, E1, N2, E2, Ty, Ix>(
+    g1: &Graph<N1, E1, Ty, Ix>,
+    g2: &Graph<N2, E2, Ty, Ix>,
+) -> Graph<(N1, N2), ProductEdge<E1, E2>, Ty, Ix>
+where
+    N1: Clone,
+    E1: Clone,
+    N2: Clone,
+    E2: Clone,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    let n2 = g2.node_count();
+    let ix = |u: NodeIndex<Ix>, v: NodeIndex<Ix>| NodeIndex::new(u.index() * n2 + v.index());
+    let mut g = product_nodes(g1, g2);
+    g.reserve_edges(g1.edge_count() * n2 + g2.edge_count() * g1.node_count());
+    for e in g1.edge_references() {
+        for v in g2.node_indices() {
+            let weight = ProductEdge::First(e.weight().clone());
+            g.add_edge(ix(e.source(), v), ix(e.target(), v), weight);
+        }
+    }
+    for u in g1.node_indices() {
+        for e in g2.edge_references() {
+            let weight = ProductEdge::Second(e.weight().clone());
+            g.add_edge(ix(u, e.source()), ix(u, e.target()), weight);
+        }
+    }
+    g
+}
+
+/// Return the tensor (categorical) product `g1 × g2`.
+///
+/// Every pair of nodes `(u, v)` becomes a node with the cloned data `(u, v)`.
+/// `(u1, v1)` and `(u2, v2)` are connected if `u1 → u2` is an edge of `g1`
+/// and `v1 → v2` is an edge of `g2`; the new edge carries both weights as
+/// `ProductEdge::Both`. For undirected graphs each pair of edges also yields
+/// the crossed edge between `(u1, v2)` and `(u2, v1)`.
+///
+/// The node indices are laid out like in
+/// [`cartesian_product`](fn.cartesian_product.html).
+pub fn tensor_product<N1, E1, N2, E2, Ty, Ix>(
+    g1: &Graph<N1, E1, Ty, Ix>,
+    g2: &Graph<N2, E2, Ty, Ix>,
+) -> Graph<(N1, N2), ProductEdge<E1, E2>, Ty, Ix>
+where
+    N1: Clone,
+    E1: Clone,
+    N2: Clone,
+    E2: Clone,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    let n2 = g2.node_count();
+    let ix = |u: NodeIndex<Ix>, v: NodeIndex<Ix>| NodeIndex::new(u.index() * n2 + v.index());
+    let mut g = product_nodes(g1, g2);
+    for e1 in g1.edge_references() {
+        for e2 in g2.edge_references() {
+            let weight = || ProductEdge::Both(e1.weight().clone(), e2.weight().clone());
+            let (a, b) = (e1.source(), e1.target());
+            let (c, d) = (e2.source(), e2.target());
+            g.add_edge(ix(a, c), ix(b, d), weight());
+            if !Ty::is_directed() && a != b && c != d {
+                g.add_edge(ix(a, d), ix(b, c), weight());
+            }
+        }
+    }
+    g
+}
+
+/// Create a graph without edges with a node for every pair of nodes of `g1`
+/// and `g2`, row-major.
+fn product_nodes<N1, E1, N2, E2, Ty, Ix>(
+    g1: &Graph<N1, E1, Ty, Ix>,
+    g2: &Graph<N2, E2, Ty, Ix>,
+) -> Graph<(N1, N2), ProductEdge<E1, E2>, Ty, Ix>
+where
+    N1: Clone,
+    N2: Clone,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    let mut g = Graph::with_capacity(g1.node_count() * g2.node_count(), 0);
+    for u in g1.node_indices() {
+        for v in g2.node_indices() {
+            g.add_node((g1[u].clone(), g2[v].clone()));
+        }
+    }
+    g
+}
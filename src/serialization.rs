@@ -0,0 +1,81 @@
+//! `serde` support for `Graph`, enabled by the `serde` feature.
+//!
+//! A graph is serialized as
+//! `{ "nodes": [...], "edges": [{ "source": u, "target": v, "weight": w }, ...] }`,
+//! with node indices encoded as `u64` so the format doesn't depend on `Ix`.
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use graph::{EdgeType, Graph, IndexType, NodeIndex};
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Graph")]
+struct SerGraph<N, E> {
+    nodes: Vec<N>,
+    edges: Vec<SerEdge<E>>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Edge")]
+struct SerEdge<E> {
+    source: u64,
+    target: u64,
+    weight: E,
+}
+
+impl<N, E, Ty, Ix> Serialize for Graph<N, E, Ty, Ix>
+where
+    N: Serialize,
+    E: Serialize,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SerGraph {
+            nodes: self.raw_nodes().iter().map(|n| &n.data).collect(),
+            edges: self
+                .raw_edges()
+                .iter()
+                .map(|e| SerEdge {
+                    source: e.source().index() as u64,
+                    target: e.target().index() as u64,
+                    weight: &e.weight,
+                })
+                .collect(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, N, E, Ty, Ix> Deserialize<'de> for Graph<N, E, Ty, Ix>
+where
+    N: Deserialize<'de>,
+    E: Deserialize<'de>,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let SerGraph { nodes, edges } = SerGraph::<N, E>::deserialize(deserializer)?;
+        if nodes.len() > <Ix as IndexType>::max().index()
+            || edges.len() > <Ix as IndexType>::max().index()
+        {
+            return Err(D::Error::custom("graph too large for its index type"));
+        }
+        let mut g = Graph::with_capacity(nodes.len(), edges.len());
+        for data in nodes {
+            g.add_node(data);
+        }
+        for edge in edges {
+            let node_count = g.node_count() as u64;
+            if edge.source >= node_count || edge.target >= node_count {
+                return Err(D::Error::custom(format!(
+                    "edge ({}, {}) has an endpoint out of bounds (node count {})",
+                    edge.source, edge.target, node_count
+                )));
+            }
+            let (a, b) = (edge.source as usize, edge.target as usize);
+            g.add_edge(NodeIndex::new(a), NodeIndex::new(b), edge.weight);
+        }
+        Ok(g)
+    }
+}
@@ -0,0 +1,445 @@
+//! `StableGraph` keeps indices stable across removals.
+use std::iter;
+use std::marker::PhantomData;
+use std::ops::{Index, IndexMut};
+use std::slice;
+
+use graph::Direction::Outgoing;
+use graph::{
+    DefaultIx, Directed, Direction, Edge, EdgeIndex, EdgeType, IndexType, Node, NodeIndex,
+    Undirected, DIRECTIONS,
+};
+
+/// `StableGraph<N, E, Ty, Ix>` is a graph datastructure using an adjacency
+/// list representation, just like `Graph`, but with stable indices.
+///
+/// Removing a node or an edge marks its slot as vacant instead of moving
+/// the last element into its place, so **all other node and edge indices
+/// stay valid**. Vacant slots are skipped by all iterators and are not
+/// counted by `.node_count()` and `.edge_count()`.
+///
+/// The price is that the index range is no longer compact: after removals,
+/// the largest index may be larger than the number of live elements.
+#[derive(Debug)]
+pub struct StableGraph<N, E, Ty = Directed, Ix = DefaultIx> {
+    nodes: Vec<Option<Node<N, Ix>>>,
+    edges: Vec<Option<Edge<E, Ix>>>,
+    node_count: usize,
+    edge_count: usize,
+    ty: PhantomData<Ty>,
+}
+
+/// A `StableGraph` with directed edges.
+pub type StableDiGraph<N, E, Ix = DefaultIx> = StableGraph<N, E, Directed, Ix>;
+/// A `StableGraph` with undirected edges.
+pub type StableUnGraph<N, E, Ix = DefaultIx> = StableGraph<N, E, Undirected, Ix>;
+
+impl<N, E> StableGraph<N, E, Directed> {
+    /// Create a new `StableGraph` with directed edges.
+    ///
+    /// This is a convenience method. Use `StableGraph::with_capacity` or
+    /// `StableGraph::default` for a constructor that is generic in all the
+    /// type parameters of `StableGraph`
+    pub fn new() -> Self {
+        Self::with_capacity(0, 0)
+    }
+}
+impl<N, E> StableGraph<N, E, Undirected> {
+    /// Create a new `StableGraph` with undirected edges.
+    ///
+    /// This is a convenience method. Use `StableGraph::with_capacity` or
+    /// `StableGraph::default` for a constructor that is generic in all the
+    /// type parameters of `StableGraph`
+    pub fn new_undirected() -> Self {
+        Self::with_capacity(0, 0)
+    }
+}
+impl<N, E, Ty: EdgeType, Ix: IndexType> StableGraph<N, E, Ty, Ix> {
+    /// Create a new `StableGraph` with estimated capacity.
+    pub fn with_capacity(nodes: usize, edges: usize) -> Self {
+        StableGraph {
+            nodes: Vec::with_capacity(nodes),
+            edges: Vec::with_capacity(edges),
+            node_count: 0,
+            edge_count: 0,
+            ty: PhantomData,
+        }
+    }
+    /// Return the number of nodes (vertices) in the graph.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn node_count(&self) -> usize {
+        self.node_count
+    }
+    /// Return the number of edges in the graph.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+    /// Whether the graph has directed edges or not.
+    #[inline]
+    pub fn is_directed(&self) -> bool {
+        Ty::is_directed()
+    }
+    /// Add a node with associated data `data` to the graph.
+    ///
+    /// Computes in **O(1)** time.
+    ///
+    /// Return the index of the new node.
+    ///
+    /// **Panics** if the graph is at the maximum number of nodes for
+    /// its index type (N/A if usize)
+    pub fn add_node(&mut self, data: N) -> NodeIndex<Ix> {
+        let node_idx = NodeIndex::new(self.nodes.len());
+        assert!(NodeIndex::end() != node_idx);
+        self.nodes.push(Some(Node {
+            data,
+            next: [EdgeIndex::end(), EdgeIndex::end()],
+        }));
+        self.node_count += 1;
+        node_idx
+    }
+    /// Return `true` if the node `a` exists in the graph.
+    pub fn contains_node(&self, a: NodeIndex<Ix>) -> bool {
+        self.get_node(a).is_some()
+    }
+    /// Access the data for node `a`.
+    ///
+    /// Also available with indexing syntax: `&graph[a]`.
+    pub fn node_data(&self, a: NodeIndex<Ix>) -> Option<&N> {
+        self.get_node(a).map(|n| &n.data)
+    }
+    /// Access the data for node `a`, mutably.
+    ///
+    /// Also available with indexing syntax: `&mut graph[a]`.
+    pub fn node_data_mut(&mut self, a: NodeIndex<Ix>) -> Option<&mut N> {
+        self.nodes
+            .get_mut(a.index())
+            .and_then(|n| n.as_mut())
+            .map(|n| &mut n.data)
+    }
+    /// Add an edge from `a` to `b` to the graph, with its associated data `weight`.
+    ///
+    /// Return the index of the new edge.
+    ///
+    /// Computes in **O(1)** time.
+    ///
+    /// **Panics** if any of the nodes don't exist.<br>
+    /// **Panics** if the graph is at the maximum number of edges for its index
+    /// type (N/A if usize).
+    pub fn add_edge(&mut self, a: NodeIndex<Ix>, b: NodeIndex<Ix>, weight: E) -> EdgeIndex<Ix> {
+        let edge_idx = EdgeIndex::new(self.edges.len());
+        assert!(EdgeIndex::end() != edge_idx);
+        if !self.contains_node(a) || !self.contains_node(b) {
+            panic!("StableGraph::add_edge(): node indices out of bound");
+        }
+        let next = [self.node_next(a)[0], self.node_next(b)[1]];
+        self.node_next_mut(a)[0] = edge_idx;
+        self.node_next_mut(b)[1] = edge_idx;
+        self.edges.push(Some(Edge {
+            weight,
+            node: [a, b],
+            next,
+        }));
+        self.edge_count += 1;
+        edge_idx
+    }
+    /// Access the weight for edge `e`.
+    ///
+    /// Also available with indexing syntax: `&graph[e]`.
+    pub fn edge_weight(&self, e: EdgeIndex<Ix>) -> Option<&E> {
+        self.get_edge(e).map(|ed| &ed.weight)
+    }
+    /// Access the weight for edge `e`, mutably.
+    ///
+    /// Also available with indexing syntax: `&mut graph[e]`.
+    pub fn edge_weight_mut(&mut self, e: EdgeIndex<Ix>) -> Option<&mut E> {
+        self.edges
+            .get_mut(e.index())
+            .and_then(|ed| ed.as_mut())
+            .map(|ed| &mut ed.weight)
+    }
+    /// Access the source and target nodes for `e`.
+    pub fn edge_endpoints(&self, e: EdgeIndex<Ix>) -> Option<(NodeIndex<Ix>, NodeIndex<Ix>)> {
+        self.get_edge(e).map(|ed| (ed.source(), ed.target()))
+    }
+    /// Remove `a` from the graph if it exists, and return its data.
+    /// If it doesn't exist in the graph, return `None`.
+    ///
+    /// All edges with an endpoint in `a` are removed as well.
+    /// No other node or edge index is invalidated.
+    ///
+    /// Computes in **O(e')** time, where **e'** is the number of affected
+    /// edges, including *n* calls to `.remove_edge()` where *n* is the number
+    /// of edges with an endpoint in `a`.
+    pub fn remove_node(&mut self, a: NodeIndex<Ix>) -> Option<N> {
+        if !self.contains_node(a) {
+            return None;
+        }
+        for d in &DIRECTIONS {
+            let k = d.index();
+            loop {
+                let next = self.node_next(a)[k];
+                if next == EdgeIndex::end() {
+                    break;
+                }
+                let ret = self.remove_edge(next);
+                debug_assert!(ret.is_some());
+                let _ = ret;
+            }
+        }
+        self.node_count -= 1;
+        self.nodes[a.index()].take().map(|n| n.data)
+    }
+    /// Remove an edge and return its edge weight, or `None` if it didn't exist.
+    ///
+    /// No other edge index is invalidated.
+    ///
+    /// Computes in **O(e')** time, where **e'** is the size of the two edge
+    /// lists the edge is part of.
+    pub fn remove_edge(&mut self, e: EdgeIndex<Ix>) -> Option<E> {
+        let (edge_node, edge_next) = match self.get_edge(e) {
+            None => return None,
+            Some(x) => (x.node, x.next),
+        };
+        for &d in &DIRECTIONS {
+            let k = d.index();
+            if self.node_next(edge_node[k])[k] == e {
+                self.node_next_mut(edge_node[k])[k] = edge_next[k];
+                continue;
+            }
+            let mut cur = self.node_next(edge_node[k])[k];
+            while let Some(Some(edge)) = self.edges.get_mut(cur.index()) {
+                if edge.next[k] == e {
+                    edge.next[k] = edge_next[k];
+                    break;
+                }
+                cur = edge.next[k];
+            }
+        }
+        self.edge_count -= 1;
+        self.edges[e.index()].take().map(|ed| ed.weight)
+    }
+    /// Return an iterator of all nodes with an edge starting from `a`.
+    ///
+    /// - `Directed`: Outgoing edges from `a`.
+    /// - `Undirected`: All edges from or to `a`.
+    ///
+    /// Produces an empty iterator if the node doesn't exist.<br>
+    /// Iterator element type is `NodeIndex<Ix>`.
+    pub fn neighbors(&self, a: NodeIndex<Ix>) -> Neighbors<'_, E, Ix> {
+        self.neighbors_directed(a, Outgoing)
+    }
+    /// Return an iterator of all neighbors that have an edge between them and `a`,
+    /// in the specified direction.
+    /// If the graph's edges are undirected, this is equivalent to *.neighbors(a)*.
+    ///
+    /// Produces an empty iterator if the node doesn't exist.<br>
+    /// Iterator element type is `NodeIndex<Ix>`.
+    pub fn neighbors_directed(&self, a: NodeIndex<Ix>, dir: Direction) -> Neighbors<'_, E, Ix> {
+        let mut iter = self.neighbors_undirected(a);
+        if self.is_directed() {
+            let k = dir.index();
+            iter.next[1 - k] = EdgeIndex::end();
+            iter.skip_start = NodeIndex::end();
+        }
+        iter
+    }
+    /// Return an iterator of all neighbors that have an edge between them and `a`,
+    /// in either direction.
+    ///
+    /// Produces an empty iterator if the node doesn't exist.<br>
+    /// Iterator element type is `NodeIndex<Ix>`.
+    pub fn neighbors_undirected(&self, a: NodeIndex<Ix>) -> Neighbors<'_, E, Ix> {
+        Neighbors {
+            skip_start: a,
+            edges: &self.edges,
+            next: match self.get_node(a) {
+                None => [EdgeIndex::end(), EdgeIndex::end()],
+                Some(n) => n.next,
+            },
+        }
+    }
+    /// Lookup an edge from `a` to `b`.
+    ///
+    /// Computes in **O(e')** time, where **e'** is the number of edges connected
+    /// to `a` (and `b` if the graph edges are undirected).
+    pub fn find_edge(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> Option<EdgeIndex<Ix>> {
+        let node = self.get_node(a)?;
+        for &d in &DIRECTIONS {
+            if self.is_directed() && d != Outgoing {
+                break;
+            }
+            let k = d.index();
+            let mut edix = node.next[k];
+            while let Some(edge) = self.get_edge(edix) {
+                if edge.node[1 - k] == b {
+                    return Some(edix);
+                }
+                edix = edge.next[k];
+            }
+        }
+        None
+    }
+    /// Lookup if there is an edge from `a` to `b`.
+    pub fn contains_edge(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> bool {
+        self.find_edge(a, b).is_some()
+    }
+    /// Return an iterator over the indices of all live nodes, in index order.
+    pub fn node_indices(&self) -> NodeIndices<'_, N, Ix> {
+        NodeIndices {
+            iter: self.nodes.iter().enumerate(),
+            ty: PhantomData,
+        }
+    }
+    /// Return an iterator over the indices of all live edges, in index order.
+    pub fn edge_indices(&self) -> EdgeIndices<'_, E, Ix> {
+        EdgeIndices {
+            iter: self.edges.iter().enumerate(),
+            ty: PhantomData,
+        }
+    }
+    /// Remove all nodes and edges.
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.edges.clear();
+        self.node_count = 0;
+        self.edge_count = 0;
+    }
+
+    fn get_node(&self, a: NodeIndex<Ix>) -> Option<&Node<N, Ix>> {
+        self.nodes.get(a.index()).and_then(|n| n.as_ref())
+    }
+    fn get_edge(&self, e: EdgeIndex<Ix>) -> Option<&Edge<E, Ix>> {
+        self.edges.get(e.index()).and_then(|ed| ed.as_ref())
+    }
+    fn node_next(&self, a: NodeIndex<Ix>) -> [EdgeIndex<Ix>; 2] {
+        self.get_node(a).expect("node must exist").next
+    }
+    fn node_next_mut(&mut self, a: NodeIndex<Ix>) -> &mut [EdgeIndex<Ix>; 2] {
+        &mut self.nodes[a.index()]
+            .as_mut()
+            .expect("node must exist")
+            .next
+    }
+}
+impl<N, E, Ty, Ix> Default for StableGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn default() -> Self {
+        Self::with_capacity(0, 0)
+    }
+}
+/// Index the `StableGraph` by `NodeIndex` to access node data.
+///
+/// **Panics** if the node doesn't exist.
+impl<N, E, Ty, Ix> Index<NodeIndex<Ix>> for StableGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Output = N;
+    fn index(&self, index: NodeIndex<Ix>) -> &N {
+        self.node_data(index).unwrap()
+    }
+}
+/// Index the `StableGraph` by `NodeIndex` to access node data.
+///
+/// **Panics** if the node doesn't exist.
+impl<N, E, Ty, Ix> IndexMut<NodeIndex<Ix>> for StableGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn index_mut(&mut self, index: NodeIndex<Ix>) -> &mut N {
+        self.node_data_mut(index).unwrap()
+    }
+}
+/// Index the `StableGraph` by `EdgeIndex` to access edge weights.
+///
+/// **Panics** if the edge doesn't exist.
+impl<N, E, Ty, Ix> Index<EdgeIndex<Ix>> for StableGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Output = E;
+    fn index(&self, index: EdgeIndex<Ix>) -> &E {
+        self.edge_weight(index).unwrap()
+    }
+}
+/// Index the `StableGraph` by `EdgeIndex` to access edge weights.
+///
+/// **Panics** if the edge doesn't exist.
+impl<N, E, Ty, Ix> IndexMut<EdgeIndex<Ix>> for StableGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn index_mut(&mut self, index: EdgeIndex<Ix>) -> &mut E {
+        self.edge_weight_mut(index).unwrap()
+    }
+}
+
+/// Iterator over the neighbors of a node in a `StableGraph`.
+///
+/// Iterator element type is `NodeIndex<Ix>`.
+pub struct Neighbors<'a, E: 'a, Ix: 'a = DefaultIx> {
+    skip_start: NodeIndex<Ix>,
+    edges: &'a [Option<Edge<E, Ix>>],
+    next: [EdgeIndex<Ix>; 2],
+}
+impl<'a, E, Ix> Iterator for Neighbors<'a, E, Ix>
+where
+    Ix: IndexType,
+{
+    type Item = NodeIndex<Ix>;
+    fn next(&mut self) -> Option<NodeIndex<Ix>> {
+        // first any outgoing edges
+        if let Some(Some(edge)) = self.edges.get(self.next[0].index()) {
+            self.next[0] = edge.next[0];
+            return Some(edge.node[1]);
+        }
+        // then incoming edges, skipping selfloops already seen as outgoing
+        while let Some(Some(edge)) = self.edges.get(self.next[1].index()) {
+            self.next[1] = edge.next[1];
+            if edge.node[0] != self.skip_start {
+                return Some(edge.node[0]);
+            }
+        }
+        None
+    }
+}
+
+/// Iterator over the indices of the live nodes of a `StableGraph`.
+pub struct NodeIndices<'a, N: 'a, Ix: 'a = DefaultIx> {
+    iter: iter::Enumerate<slice::Iter<'a, Option<Node<N, Ix>>>>,
+    ty: PhantomData<fn() -> Ix>,
+}
+impl<'a, N, Ix: IndexType> Iterator for NodeIndices<'a, N, Ix> {
+    type Item = NodeIndex<Ix>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .by_ref()
+            .find(|&(_, n)| n.is_some())
+            .map(|(i, _)| NodeIndex::new(i))
+    }
+}
+
+/// Iterator over the indices of the live edges of a `StableGraph`.
+pub struct EdgeIndices<'a, E: 'a, Ix: 'a = DefaultIx> {
+    iter: iter::Enumerate<slice::Iter<'a, Option<Edge<E, Ix>>>>,
+    ty: PhantomData<fn() -> Ix>,
+}
+impl<'a, E, Ix: IndexType> Iterator for EdgeIndices<'a, E, Ix> {
+    type Item = EdgeIndex<Ix>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .by_ref()
+            .find(|&(_, e)| e.is_some())
+            .map(|(i, _)| EdgeIndex::new(i))
+    }
+}
@@ -0,0 +1,264 @@
+#![allow(unused_imports)]
+
+use super::Direction::{Incoming, Outgoing};
+use super::*;
+use algo::*;
+
+#[test]
+fn zero_one_bfs_distances() {
+    let n = NodeIndex::new;
+    // a 3 × 3 grid with weight 1 moves and a free teleporter from 0 to 8
+    let mut g: DiGraph<(), u8> = ::generators::grid_graph(3, 3);
+    for w in g.edge_weights_mut() {
+        *w = 1;
+    }
+    g.add_edge(n(0), n(8), 0);
+    g.add_edge(n(8), n(2), 0);
+    let dist = zero_one_bfs(&g, n(0)).unwrap();
+    assert_eq!(
+        dist,
+        vec![0, 1, 0, 1, 2, 1, 2, 3, 0]
+            .into_iter()
+            .map(Some)
+            .collect::<Vec<_>>()
+    );
+    // nothing leads back to 0
+    assert_eq!(zero_one_bfs(&g, n(4)).unwrap()[0], None);
+
+    let ug = g.into_edge_type::<Undirected>();
+    let dist = zero_one_bfs(&ug, n(0)).unwrap();
+    assert_eq!(
+        dist,
+        vec![0, 1, 0, 1, 2, 1, 2, 1, 0]
+            .into_iter()
+            .map(Some)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn zero_one_bfs_invalid_weight() {
+    let mut g: DiGraph<(), u8> = Graph::new();
+    let a = g.add_node(());
+    let b = g.add_node(());
+    g.add_edge(a, b, 1);
+    let e = g.add_edge(b, b, 2);
+    let err = zero_one_bfs(&g, a).unwrap_err();
+    assert_eq!(err, InvalidWeight { edge: e, weight: 2 });
+    assert_eq!(err.to_string(), "edge 1 has weight 2, expected 0 or 1");
+}
+
+#[cfg(test)]
+fn capacities() -> (DiGraph<(), u64>, Vec<NodeIndex>) {
+    let mut g: DiGraph<(), u64> = Graph::new();
+    let n: Vec<_> = (0..6).map(|_| g.add_node(())).collect();
+    g.add_edge(n[0], n[1], 10);
+    g.add_edge(n[1], n[2], 2);
+    g.add_edge(n[0], n[3], 4);
+    g.add_edge(n[3], n[2], 6);
+    g.add_edge(n[1], n[4], 7);
+    g.add_edge(n[4], n[2], 3);
+    g.add_edge(n[2], n[0], 100);
+    (g, n)
+}
+
+#[test]
+fn widest() {
+    let (g, n) = capacities();
+    assert_eq!(
+        widest_path(&g, n[0], n[2]),
+        Some((4, vec![n[0], n[3], n[2]]))
+    );
+    assert_eq!(
+        widest_path(&g, n[0], n[4]),
+        Some((7, vec![n[0], n[1], n[4]]))
+    );
+    assert_eq!(widest_path(&g, n[0], n[0]), Some((u64::MAX, vec![n[0]])));
+    assert_eq!(widest_path(&g, n[0], n[5]), None);
+    assert_eq!(
+        widest_path(&g, n[2], n[1]),
+        Some((10, vec![n[2], n[0], n[1]]))
+    );
+
+    let ug = g.into_edge_type::<Undirected>();
+    assert_eq!(
+        widest_path(&ug, n[2], n[1]),
+        Some((10, vec![n[2], n[0], n[1]]))
+    );
+    assert_eq!(
+        widest_path(&ug, n[4], n[3]),
+        Some((6, vec![n[4], n[1], n[0], n[2], n[3]]))
+    );
+}
+
+#[test]
+fn minimax() {
+    let (g, n) = capacities();
+    assert_eq!(
+        minimax_path(&g, n[0], n[2]),
+        Some((6, vec![n[0], n[3], n[2]]))
+    );
+    assert_eq!(
+        minimax_path(&g, n[0], n[4]),
+        Some((10, vec![n[0], n[1], n[4]]))
+    );
+    assert_eq!(minimax_path(&g, n[3], n[3]), Some((0, vec![n[3]])));
+    assert_eq!(minimax_path(&g, n[5], n[0]), None);
+
+    let ug = g.into_edge_type::<Undirected>();
+    assert_eq!(
+        minimax_path(&ug, n[0], n[1]),
+        Some((6, vec![n[0], n[3], n[2], n[1]]))
+    );
+}
+
+/// The smallest and largest weight on every simple path from `s` to `t`,
+/// found by trying all of them.
+#[cfg(test)]
+fn path_extremes<Ty: EdgeType>(
+    g: &Graph<(), u64, Ty>,
+    s: NodeIndex,
+    t: NodeIndex,
+) -> Vec<(u64, u64)> {
+    fn visit<Ty: EdgeType>(
+        g: &Graph<(), u64, Ty>,
+        a: NodeIndex,
+        t: NodeIndex,
+        on_path: &mut Vec<NodeIndex>,
+        (min, max): (u64, u64),
+        out: &mut Vec<(u64, u64)>,
+    ) {
+        if a == t {
+            out.push((min, max));
+            return;
+        }
+        on_path.push(a);
+        for (b, &w) in g.neighbors_weighted(a, Outgoing) {
+            if !on_path.contains(&b) {
+                visit(g, b, t, on_path, (min.min(w), max.max(w)), out);
+            }
+        }
+        on_path.pop();
+    }
+    let mut out = Vec::new();
+    visit(g, s, t, &mut Vec::new(), (u64::MAX, 0), &mut out);
+    out
+}
+
+/// The value `path` achieves, using the best edge between each pair of
+/// consecutive nodes.
+#[cfg(test)]
+fn path_value<Ty: EdgeType>(g: &Graph<(), u64, Ty>, path: &[NodeIndex], widest: bool) -> u64 {
+    let mut sorted = path.to_vec();
+    sorted.sort();
+    sorted.dedup();
+    assert_eq!(sorted.len(), path.len(), "path is not simple");
+    let hops = path.windows(2).map(|pair| {
+        let weights = g
+            .neighbors_weighted(pair[0], Outgoing)
+            .filter(|&(b, _)| b == pair[1])
+            .map(|(_, &w)| w);
+        if widest {
+            weights.max().expect("no edge between consecutive nodes")
+        } else {
+            weights.min().expect("no edge between consecutive nodes")
+        }
+    });
+    if widest {
+        hops.min().unwrap_or(u64::MAX)
+    } else {
+        hops.max().unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+fn check_bottleneck_paths<Ty: EdgeType>(g: &Graph<(), u64, Ty>) {
+    for s in g.node_indices() {
+        for t in g.node_indices() {
+            let extremes = path_extremes(g, s, t);
+            let widest = extremes.iter().map(|&(min, _)| min).max();
+            let minimax = extremes.iter().map(|&(_, max)| max).min();
+            for (result, expected, is_widest) in [
+                (widest_path(g, s, t), widest, true),
+                (minimax_path(g, s, t), minimax, false),
+            ] {
+                match result {
+                    None => assert_eq!(expected, None, "{:?} -> {:?}", s, t),
+                    Some((value, path)) => {
+                        assert_eq!(Some(value), expected, "{:?} -> {:?}", s, t);
+                        assert_eq!(path.first(), Some(&s));
+                        assert_eq!(path.last(), Some(&t));
+                        assert_eq!(path_value(g, &path, is_widest), value);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn bottleneck_brute_force() {
+    let (g, _) = capacities();
+    check_bottleneck_paths(&g);
+    check_bottleneck_paths(&g.into_edge_type::<Undirected>());
+}
+
+#[test]
+fn simple_paths_complete() {
+    let n = NodeIndex::new;
+    let g: Graph<(), ()> = ::generators::complete_graph(5);
+    let count = |min, max| all_simple_paths(&g, n(0), n(4), min, max).count();
+    // 1 + 3 + 3 * 2 + 3 * 2 * 1 paths with 0, 1, 2 and 3 intermediate nodes
+    assert_eq!(count(0, None), 16);
+    assert_eq!(count(0, Some(0)), 1);
+    assert_eq!(count(0, Some(1)), 4);
+    assert_eq!(count(2, None), 12);
+    assert_eq!(count(2, Some(2)), 6);
+    assert_eq!(count(3, Some(2)), 0);
+    assert_eq!(count(4, None), 0);
+
+    for path in all_simple_paths(&g, n(0), n(4), 1, Some(2)) {
+        assert!(path.len() == 3 || path.len() == 4);
+        assert_eq!(path[0], n(0));
+        assert_eq!(*path.last().unwrap(), n(4));
+        let mut sorted = path.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted.len(), path.len());
+    }
+
+    let ug: UnGraph<(), ()> = ::generators::complete_graph(5);
+    assert_eq!(all_simple_paths(&ug, n(0), n(4), 0, None).count(), 16);
+}
+
+#[test]
+fn simple_paths_cycles() {
+    let n = NodeIndex::new;
+    let mut g: Graph<(), ()> = ::generators::cycle_graph(4);
+    g.add_edge(n(1), n(1), ());
+    g.add_edge(n(2), n(0), ());
+    let paths: Vec<_> = all_simple_paths(&g, n(0), n(3), 0, None).collect();
+    assert_eq!(paths, vec![vec![n(0), n(1), n(2), n(3)]]);
+    assert_eq!(all_simple_paths(&g, n(0), n(0), 0, None).count(), 0);
+    assert_eq!(all_simple_paths(&g, n(3), n(2), 0, None).count(), 1);
+
+    let ug: UnGraph<(), ()> = ::generators::cycle_graph(6);
+    let paths: Vec<_> = all_simple_paths(&ug, n(0), n(2), 0, None).collect();
+    assert_eq!(paths.len(), 2);
+    assert!(paths.contains(&vec![n(0), n(1), n(2)]));
+    assert!(paths.contains(&vec![n(0), n(5), n(4), n(3), n(2)]));
+}
+
+#[test]
+fn simple_paths_lazy() {
+    let n = NodeIndex::new;
+    // far too many paths to enumerate
+    let g: UnGraph<(), ()> = ::generators::complete_graph(30);
+    let first: Vec<_> = all_simple_paths(&g, n(0), n(1), 0, None).take(3).collect();
+    assert_eq!(first.len(), 3);
+    let short: Vec<_> = all_simple_paths(&g, n(0), n(1), 2, Some(2))
+        .take(100)
+        .collect();
+    assert_eq!(short.len(), 100);
+    assert!(short.iter().all(|path| path.len() == 4));
+}
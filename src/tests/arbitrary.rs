@@ -0,0 +1,91 @@
+#![allow(unused_imports)]
+
+use super::Direction::{Incoming, Outgoing};
+use super::*;
+use quickcheck::{quickcheck, Arbitrary, Gen};
+
+/// Check that the edge lists of every node together hold every edge exactly
+/// once per direction, and that every edge is found from its endpoints.
+fn assert_consistent<Ty: EdgeType>(g: &Graph<(), u8, Ty>) {
+    for &dir in &[Outgoing, Incoming] {
+        let k = dir.index();
+        let mut seen = vec![0; g.edge_count()];
+        for a in g.node_indices() {
+            let mut e = g.raw_nodes()[a.index()].next_edge(dir);
+            while e != EdgeIndex::end() {
+                let edge = &g.raw_edges()[e.index()];
+                assert_eq!(edge.node[k], a);
+                seen[e.index()] += 1;
+                e = edge.next_edge(dir);
+            }
+        }
+        assert!(seen.iter().all(|&count| count == 1));
+    }
+    for edge in g.raw_edges() {
+        let (a, b) = (edge.source(), edge.target());
+        assert!(a.index() < g.node_count() && b.index() < g.node_count());
+        assert!(g.neighbors(a).any(|v| v == b));
+        assert!(g.neighbors_undirected(b).any(|v| v == a));
+    }
+}
+
+fn remove_node_keeps_edge_lists<Ty: EdgeType>(mut g: Graph<(), u8, Ty>, i: usize) -> bool {
+    if g.node_count() == 0 {
+        return true;
+    }
+    let a = NodeIndex::new(i % g.node_count());
+    let incident = g
+        .raw_edges()
+        .iter()
+        .filter(|e| e.source() == a || e.target() == a)
+        .count();
+    let (nodes, edges) = (g.node_count(), g.edge_count());
+    g.remove_node(a);
+    assert_eq!(g.node_count(), nodes - 1);
+    assert_eq!(g.edge_count(), edges - incident);
+    assert_consistent(&g);
+    true
+}
+
+#[test]
+fn arbitrary_graphs_are_consistent() {
+    fn prop(g: DiGraph<(), u8>, h: UnGraph<(), u8>) -> bool {
+        assert_consistent(&g);
+        assert_consistent(&h);
+        true
+    }
+    quickcheck(prop as fn(_, _) -> bool);
+}
+
+#[test]
+fn remove_node_directed() {
+    quickcheck(remove_node_keeps_edge_lists::<Directed> as fn(_, _) -> bool);
+}
+
+#[test]
+fn remove_node_undirected() {
+    quickcheck(remove_node_keeps_edge_lists::<Undirected> as fn(_, _) -> bool);
+}
+
+#[test]
+fn shrink_removes_edges_then_nodes() {
+    let mut g: DiGraph<(), u8> = Graph::new();
+    let a = g.add_node(());
+    let b = g.add_node(());
+    g.add_edge(a, b, 1);
+    g.add_edge(b, b, 2);
+    let shrunk: Vec<_> = g
+        .shrink()
+        .map(|h| (h.node_count(), h.edge_count()))
+        .collect();
+    assert_eq!(shrunk, vec![(2, 1), (2, 1), (1, 0), (1, 1)]);
+    for h in g.shrink() {
+        assert_consistent(&h);
+    }
+
+    let mut gen = Gen::new(20);
+    for _ in 0..20 {
+        let g = UnGraph::<(), u8>::arbitrary(&mut gen);
+        assert!(g.node_count() <= 20);
+    }
+}
@@ -0,0 +1,60 @@
+#![allow(unused_imports)]
+
+use super::Direction::{Incoming, Outgoing};
+use super::*;
+use binary::*;
+
+#[test]
+fn binary_round_trip() {
+    let mut g: UnGraph<String, u32> = Graph::new_undirected();
+    let a = g.add_node("a".to_string());
+    let b = g.add_node("b".to_string());
+    g.add_edge(a, b, 1);
+    g.add_edge(b, b, 2);
+
+    let mut bytes = Vec::new();
+    g.write_binary(&mut bytes).unwrap();
+    assert_eq!(&bytes[..5], b"GRAP\x01");
+    assert_eq!(&bytes[5..9], [2, 0, 0, 0]);
+
+    let h: UnGraph<String, u32> = Graph::read_binary(&bytes[..]).unwrap();
+    assert_eq!(
+        h.raw_nodes().iter().map(|n| &n.data).collect::<Vec<_>>(),
+        g.raw_nodes().iter().map(|n| &n.data).collect::<Vec<_>>()
+    );
+    for (e, f) in g.raw_edges().iter().zip(h.raw_edges()) {
+        assert_eq!((e.source(), e.target()), (f.source(), f.target()));
+        assert_eq!(e.weight, f.weight);
+    }
+    assert_eq!(h.edge_count(), 2);
+}
+
+#[test]
+fn binary_errors() {
+    let g: DiGraph<(), ()> = Graph::new();
+    let mut bytes = Vec::new();
+    g.write_binary(&mut bytes).unwrap();
+
+    let mut bad = bytes.clone();
+    bad[0] = b'X';
+    match DiGraph::<(), ()>::read_binary(&bad[..]) {
+        Err(BinaryError::BadMagic(0x5852_4150)) => {}
+        other => panic!("unexpected {:?}", other.map(|_| ())),
+    }
+    bad = bytes.clone();
+    bad[4] = 9;
+    match DiGraph::<(), ()>::read_binary(&bad[..]) {
+        Err(BinaryError::UnsupportedVersion(9)) => {}
+        other => panic!("unexpected {:?}", other.map(|_| ())),
+    }
+    // one node, one edge pointing to a missing node
+    bad = b"GRAP\x01\x01\0\0\0\x01\0\0\0\0\0\0\0\x01\0\0\0".to_vec();
+    match DiGraph::<(), ()>::read_binary(&bad[..]) {
+        Err(BinaryError::InvalidEdge(0, 1)) => {}
+        other => panic!("unexpected {:?}", other.map(|_| ())),
+    }
+    match DiGraph::<(), ()>::read_binary(&bytes[..7]) {
+        Err(BinaryError::Io(_)) => {}
+        other => panic!("unexpected {:?}", other.map(|_| ())),
+    }
+}
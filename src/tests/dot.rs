@@ -0,0 +1,101 @@
+#![allow(unused_imports)]
+
+use super::*;
+use dot::{escape, from_dot, from_dot_undirected, Config, Dot};
+
+#[test]
+fn undirected_dot() {
+    let mut g: UnGraph<&str, &str> = Graph::new_undirected();
+    let a = g.add_node("say \"hi\"");
+    let b = g.add_node("two\nlines");
+    g.add_edge(a, b, "back\\slash");
+    assert_eq!(
+        Dot::new(&g).to_string(),
+        "graph {\n\
+         \x20   0 [label=\"say \\\"hi\\\"\"]\n\
+         \x20   1 [label=\"two\\nlines\"]\n\
+         \x20   0 -- 1 [label=\"back\\\\slash\"]\n\
+         }\n"
+    );
+}
+
+#[test]
+fn index_labels() {
+    let mut g: DiGraph<&str, &str> = Graph::new();
+    let a = g.add_node("a");
+    g.add_edge(a, a, "loop");
+    let config = [Config::NodeIndexLabel, Config::EdgeIndexLabel];
+    assert_eq!(
+        Dot::with_config(&g, &config).to_string(),
+        "digraph {\n    0 [label=\"0\"]\n    0 -> 0 [label=\"0\"]\n}\n"
+    );
+}
+
+#[test]
+fn escape_label() {
+    assert_eq!(escape("a\"b\\c\nd"), "a\\\"b\\\\c\\nd");
+    assert_eq!(escape("plain"), "plain");
+}
+
+#[test]
+fn parse_round_trip() {
+    let mut g: DiGraph<&str, &str> = Graph::new();
+    let a = g.add_node("a \"quoted\"");
+    let b = g.add_node("b\\c");
+    let c = g.add_node("two\nlines");
+    g.add_edge(a, b, "1");
+    g.add_edge(b, c, "");
+    g.add_edge(c, c, "self");
+    for text in &[Dot::new(&g).to_string(), g.to_dot_display()] {
+        let parsed = from_dot(text).unwrap();
+        assert_eq!(parsed.node_count(), 3);
+        for (n, m) in g.raw_nodes().iter().zip(parsed.raw_nodes()) {
+            assert_eq!(n.data, m.data);
+        }
+        for (e, f) in g.raw_edges().iter().zip(parsed.raw_edges()) {
+            assert_eq!(e.weight, f.weight);
+            assert_eq!((e.source(), e.target()), (f.source(), f.target()));
+        }
+    }
+}
+
+#[test]
+fn parse_statements() {
+    let g = from_dot(
+        "/* header */ strict digraph \"name\" {\n\
+         \x20   rankdir = LR; node [shape=box]\n\
+         \x20   # comment\n\
+         \x20   a -> b -> c [color=red, label=\"x\"]\n\
+         \x20   c [label=C] // trailing\n\
+         }",
+    )
+    .unwrap();
+    assert_eq!(g.node_count(), 3);
+    let data: Vec<_> = g.raw_nodes().iter().map(|n| &n.data[..]).collect();
+    assert_eq!(data, ["a", "b", "C"]);
+    assert_eq!(g.edge_count(), 2);
+    assert!(g.raw_edges().iter().all(|e| e.weight == "x"));
+
+    let ug = from_dot_undirected("graph { 1 -- 2; 2 -- 3 }").unwrap();
+    assert_eq!(ug.edge_count(), 2);
+    assert_eq!(ug.neighbors(NodeIndex::new(1)).count(), 2);
+}
+
+#[test]
+fn parse_errors() {
+    let err = from_dot("digraph {\n    a -- b\n}").err().unwrap();
+    assert_eq!((err.line, err.column), (2, 7));
+    let err = from_dot_undirected("digraph { a -> b }").err().unwrap();
+    assert_eq!((err.line, err.column), (1, 1));
+    let err = from_dot("digraph {\n  a -> \"b").err().unwrap();
+    assert_eq!((err.line, err.column), (2, 8));
+    // a backslash at the very end doesn't close the string either
+    let err = from_dot("digraph {\n  a -> \"a\\").err().unwrap();
+    assert_eq!((err.line, err.column), (2, 8));
+    assert_eq!(err.to_string(), "2:8: unterminated string");
+    let err = from_dot("digraph { a -> }").err().unwrap();
+    assert_eq!((err.line, err.column), (1, 16));
+    let err = from_dot("digraph { a").err().unwrap();
+    assert_eq!((err.line, err.column), (1, 12));
+    assert_eq!(err.to_string(), "1:12: expected `}`");
+}
@@ -0,0 +1,336 @@
+#![allow(unused_imports)]
+
+use super::Direction::{Incoming, Outgoing};
+use super::*;
+use generators::*;
+
+#[test]
+fn complete() {
+    let n = NodeIndex::new;
+    let g: UnGraph<(), ()> = complete_graph(5);
+    assert_eq!(g.node_count(), 5);
+    assert_eq!(g.edge_count(), 10);
+    assert!(g.is_simple());
+    assert!(g.contains_edge(n(4), n(1)));
+    assert_eq!(g.neighbors(n(2)).count(), 4);
+
+    let g: DiGraph<(), ()> = complete_graph(4);
+    assert_eq!(g.edge_count(), 12);
+    assert!(g.contains_edge(n(3), n(0)) && g.contains_edge(n(0), n(3)));
+    assert!(!g.contains_edge(n(1), n(1)));
+    assert_eq!(
+        complete_graph::<(), (), Directed, DefaultIx>(0).node_count(),
+        0
+    );
+}
+
+#[test]
+fn path() {
+    let n = NodeIndex::new;
+    let g: DiGraph<(), ()> = path_graph(4);
+    assert_eq!(g.node_count(), 4);
+    assert_eq!(g.edge_count(), 3);
+    assert!(g.contains_edge(n(2), n(3)));
+    assert!(!g.contains_edge(n(3), n(2)));
+    assert_eq!(g.source_nodes().collect::<Vec<_>>(), vec![n(0)]);
+    assert_eq!(
+        path_graph::<(), (), Undirected, DefaultIx>(0).edge_count(),
+        0
+    );
+}
+
+#[test]
+fn cycle() {
+    let n = NodeIndex::new;
+    let g: UnGraph<(), ()> = cycle_graph(5);
+    assert_eq!(g.node_count(), 5);
+    assert_eq!(g.edge_count(), 5);
+    assert!(g.contains_edge(n(0), n(4)));
+    assert!(g.node_indices().all(|a| g.neighbors(a).count() == 2));
+    let g: DiGraph<(), ()> = cycle_graph(2);
+    assert_eq!(g.edge_count(), 1);
+    assert!(g.is_simple());
+}
+
+#[test]
+fn star() {
+    let n = NodeIndex::new;
+    let g: UnGraph<(), ()> = star_graph(4);
+    assert_eq!(g.node_count(), 5);
+    assert_eq!(g.edge_count(), 4);
+    assert_eq!(g.neighbors(n(0)).count(), 4);
+    assert_eq!(g.neighbors(n(3)).collect::<Vec<_>>(), vec![n(0)]);
+}
+
+#[test]
+fn grid() {
+    let n = NodeIndex::new;
+    let g: DiGraph<(), ()> = grid_graph(3, 4);
+    assert_eq!(g.node_count(), 12);
+    assert_eq!(g.edge_count(), 3 * 3 + 2 * 4);
+    // row 1, column 2
+    let a = n(6);
+    assert!(g.contains_edge(a, n(7)));
+    assert!(g.contains_edge(a, n(10)));
+    assert!(g.contains_edge(n(2), a));
+    assert!(!g.contains_edge(n(7), n(8)));
+    assert!(!g.contains_edge(n(3), n(4)));
+    assert_eq!(g.neighbors_undirected(a).count(), 4);
+    assert_eq!(
+        grid_graph::<(), (), Directed, DefaultIx>(0, 5).node_count(),
+        0
+    );
+}
+
+/// Number of hops from `start` to every node, ignoring edge directions.
+fn hops<Ty: EdgeType>(g: &Graph<(), (), Ty>, start: NodeIndex) -> Vec<Option<usize>> {
+    use std::collections::VecDeque;
+
+    let mut dist = vec![None; g.node_count()];
+    dist[start.index()] = Some(0);
+    let mut queue: VecDeque<_> = Some(start).into_iter().collect();
+    while let Some(a) = queue.pop_front() {
+        let d = dist[a.index()].unwrap();
+        for b in g.neighbors_undirected(a) {
+            if dist[b.index()].is_none() {
+                dist[b.index()] = Some(d + 1);
+                queue.push_back(b);
+            }
+        }
+    }
+    dist
+}
+
+#[test]
+fn grid_8connected() {
+    let n = NodeIndex::new;
+    let g: UnGraph<(), ()> = grid_graph_8connected(3, 4);
+    assert_eq!(g.node_count(), 12);
+    assert_eq!(g.edge_count(), 3 * 3 + 2 * 4 + 2 * 2 * 3);
+    assert!(g.is_simple());
+    let degree = |a| g.neighbors(n(a)).count();
+    assert_eq!((degree(0), degree(3), degree(8), degree(11)), (3, 3, 3, 3));
+    assert_eq!((degree(1), degree(4), degree(7)), (5, 5, 5));
+    assert_eq!((degree(5), degree(6)), (8, 8));
+    // row 1, column 2
+    assert!(g.contains_edge(n(6), n(1)) && g.contains_edge(n(6), n(3)));
+    assert!(g.contains_edge(n(6), n(9)) && g.contains_edge(n(6), n(11)));
+    assert!(!g.contains_edge(n(3), n(4)));
+    assert_eq!(hops(&g, n(0))[11], Some(3));
+
+    let four: UnGraph<(), ()> = grid_graph(3, 4);
+    assert_eq!(hops(&four, n(0))[11], Some(3 + 4 - 2));
+    assert_eq!(
+        grid_graph_8connected::<(), (), Directed, DefaultIx>(1, 5).edge_count(),
+        4
+    );
+}
+
+#[test]
+fn binary() {
+    let n = NodeIndex::new;
+    let g: DiGraph<(), ()> = binary_tree(3);
+    assert_eq!(g.node_count(), 15);
+    assert_eq!(g.edge_count(), 14);
+    assert_eq!(g.neighbors(n(2)).collect::<Vec<_>>(), vec![n(6), n(5)]);
+    assert_eq!(
+        g.neighbors_directed(n(9), Incoming).collect::<Vec<_>>(),
+        vec![n(4)]
+    );
+    assert_eq!(g.sink_nodes().count(), 8);
+    assert_eq!(
+        binary_tree::<(), (), Directed, DefaultIx>(0).node_count(),
+        1
+    );
+}
+
+#[test]
+#[should_panic(expected = "depth")]
+fn binary_too_deep() {
+    binary_tree::<(), (), Directed, usize>(usize::BITS - 1);
+}
+
+#[test]
+fn bipartite() {
+    let n = NodeIndex::new;
+    let g: UnGraph<(), ()> = complete_bipartite(2, 3);
+    assert_eq!(g.node_count(), 5);
+    assert_eq!(g.edge_count(), 6);
+    assert!(g.contains_edge(n(4), n(1)));
+    assert!(!g.contains_edge(n(0), n(1)));
+    assert!(!g.contains_edge(n(2), n(3)));
+    assert_eq!(g.neighbors(n(3)).count(), 2);
+}
+
+fn edge_list<Ty: EdgeType>(g: &Graph<(), (), Ty>) -> Vec<(usize, usize)> {
+    g.raw_edges()
+        .iter()
+        .map(|e| (e.source().index(), e.target().index()))
+        .collect()
+}
+
+#[test]
+fn gnp() {
+    let g: UnGraph<(), ()> = random::gnp_random_graph(2000, 0.01, 7);
+    assert_eq!(g.node_count(), 2000);
+    // expected 19990 edges, standard deviation about 140
+    assert!((19_000..21_000).contains(&g.edge_count()));
+    assert!(g.is_simple());
+    let h: UnGraph<(), ()> = random::gnp_random_graph(2000, 0.01, 7);
+    assert_eq!(edge_list(&g), edge_list(&h));
+    let h: UnGraph<(), ()> = random::gnp_random_graph(2000, 0.01, 8);
+    assert_ne!(edge_list(&g), edge_list(&h));
+
+    let g: DiGraph<(), ()> = random::gnp_random_graph(300, 0.1, 1);
+    assert!((8_000..9_900).contains(&g.edge_count()));
+    assert!(g.is_simple());
+
+    let g: DiGraph<(), ()> = random::gnp_random_graph(100_000, 0.00001, 3);
+    assert!((80_000..120_000).contains(&g.edge_count()));
+
+    let g: DiGraph<(), ()> = random::gnp_random_graph(10, 1.0, 0);
+    assert_eq!(g.edge_count(), 90);
+    let g: UnGraph<(), ()> = random::gnp_random_graph(10, 0.0, 0);
+    assert_eq!(g.edge_count(), 0);
+}
+
+#[test]
+fn gnm() {
+    let g: UnGraph<(), ()> = random::gnm_random_graph(50, 300, 11);
+    assert_eq!(g.edge_count(), 300);
+    assert!(g.is_simple());
+    let h: UnGraph<(), ()> = random::gnm_random_graph(50, 300, 11);
+    assert_eq!(edge_list(&g), edge_list(&h));
+    let keys: Vec<_> = g
+        .raw_edges()
+        .iter()
+        .map(|e| (e.target(), e.source()))
+        .collect();
+    assert!(keys.windows(2).all(|w| w[0] < w[1]));
+
+    let g: DiGraph<(), ()> = random::gnm_random_graph(20, 370, 5);
+    assert_eq!(g.edge_count(), 370);
+    assert!(g.is_simple());
+    let keys: Vec<_> = g
+        .raw_edges()
+        .iter()
+        .map(|e| (e.source(), e.target()))
+        .collect();
+    assert!(keys.windows(2).all(|w| w[0] < w[1]));
+    let g: UnGraph<(), ()> = random::gnm_random_graph(6, 15, 5);
+    assert_eq!(g.edge_count(), 15);
+    assert!(g.is_simple());
+}
+
+#[test]
+fn barabasi_albert() {
+    let g: UnGraph<(), ()> = random::barabasi_albert(500, 3, 2);
+    assert_eq!(g.node_count(), 500);
+    assert_eq!(g.edge_count(), 3 * 497);
+    assert!(g.is_simple());
+    assert!(g.node_indices().all(|a| g.neighbors(a).count() >= 3));
+    let h: UnGraph<(), ()> = random::barabasi_albert(500, 3, 2);
+    assert_eq!(edge_list(&g), edge_list(&h));
+}
+
+#[test]
+fn watts_strogatz() {
+    let ring: UnGraph<(), ()> = random::watts_strogatz(20, 4, 0.0, 0);
+    assert_eq!(ring.edge_count(), 40);
+    assert!(ring.node_indices().all(|a| ring.neighbors(a).count() == 4));
+
+    let g: UnGraph<(), ()> = random::watts_strogatz(200, 6, 0.3, 9);
+    assert_eq!(g.edge_count(), 600);
+    assert!(g.is_simple());
+    assert_ne!(
+        edge_list(&g),
+        edge_list::<Undirected>(&random::watts_strogatz(200, 6, 0.0, 9))
+    );
+    let h: UnGraph<(), ()> = random::watts_strogatz(200, 6, 0.3, 9);
+    assert_eq!(edge_list(&g), edge_list(&h));
+
+    let g: DiGraph<(), ()> = random::watts_strogatz(5, 4, 1.0, 4);
+    assert_eq!(g.edge_count(), 10);
+    assert!(g.is_simple());
+}
+
+/// Length of the shortest cycle through `start`, if any.
+fn shortest_cycle(g: &UnGraph<(), ()>, start: NodeIndex) -> Option<usize> {
+    use std::collections::VecDeque;
+
+    // BFS remembering the parent, a non-tree edge closes a cycle
+    let mut dist = vec![None; g.node_count()];
+    let mut parent = vec![None; g.node_count()];
+    dist[start.index()] = Some(0);
+    let mut queue: VecDeque<_> = Some(start).into_iter().collect();
+    let mut best = None;
+    while let Some(a) = queue.pop_front() {
+        let d = dist[a.index()].unwrap();
+        for b in g.neighbors(a) {
+            match dist[b.index()] {
+                None => {
+                    dist[b.index()] = Some(d + 1);
+                    parent[b.index()] = Some(a);
+                    queue.push_back(b);
+                }
+                Some(e) if parent[a.index()] != Some(b) => {
+                    best = Some(best.map_or(d + e + 1, |x: usize| x.min(d + e + 1)));
+                }
+                Some(_) => {}
+            }
+        }
+    }
+    best
+}
+
+fn has_hamiltonian_cycle(g: &UnGraph<(), ()>) -> bool {
+    fn extend(g: &UnGraph<(), ()>, path: &mut Vec<NodeIndex>, used: &mut Vec<bool>) -> bool {
+        let last = *path.last().unwrap();
+        if path.len() == g.node_count() {
+            return g.contains_edge(last, path[0]);
+        }
+        for b in g.neighbors(last) {
+            if !used[b.index()] {
+                used[b.index()] = true;
+                path.push(b);
+                if extend(g, path, used) {
+                    return true;
+                }
+                path.pop();
+                used[b.index()] = false;
+            }
+        }
+        false
+    }
+    let mut used = vec![false; g.node_count()];
+    used[0] = true;
+    extend(g, &mut vec![NodeIndex::new(0)], &mut used)
+}
+
+#[test]
+fn named_graphs() {
+    // (graph, nodes, edges, degree, girth, diameter, Hamiltonian)
+    let cases: Vec<(UnGraph<(), ()>, _, _, _, _, _, _)> = vec![
+        (named::petersen(), 10, 15, 3, 5, 2, false),
+        (named::k5(), 5, 10, 4, 3, 1, true),
+        (named::complete_bipartite_k33(), 6, 9, 3, 4, 2, true),
+        (named::dodecahedron(), 20, 30, 3, 5, 5, true),
+        (named::icosahedron(), 12, 30, 5, 3, 3, true),
+    ];
+    for (g, nodes, edges, degree, girth, diameter, hamiltonian) in cases {
+        assert_eq!(g.node_count(), nodes);
+        assert_eq!(g.edge_count(), edges);
+        assert!(g.is_simple());
+        assert!(g.node_indices().all(|a| g.neighbors(a).count() == degree));
+        let cycles = g.node_indices().filter_map(|a| shortest_cycle(&g, a));
+        assert_eq!(cycles.min(), Some(girth));
+        let eccentricities = g
+            .node_indices()
+            .map(|a| hops(&g, a).into_iter().map(Option::unwrap).max().unwrap());
+        assert_eq!(eccentricities.max(), Some(diameter));
+        assert_eq!(has_hamiltonian_cycle(&g), hamiltonian);
+    }
+
+    let g: DiGraph<(), ()> = named::petersen();
+    assert!(g.raw_edges().iter().all(|e| e.source() < e.target()));
+}
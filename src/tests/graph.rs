@@ -123,8 +123,8 @@ fn empty_graph() {
     test_func!(gs => .raw_edges().is_empty() == true);
     test_func!(gs => .first_edge(NodeIndex::new(0), Outgoing) == None);
     test_func!(gs => .first_edge(NodeIndex::new(0), Incoming) == None);
-    test_func!(gs => .next_edge(NodeIndex::new(0), Outgoing) == None);
-    test_func!(gs => .next_edge(NodeIndex::new(0), Incoming) == None);
+    test_func!(gs => .next_edge(EdgeIndex::new(0), Outgoing) == None);
+    test_func!(gs => .next_edge(EdgeIndex::new(0), Incoming) == None);
 }
 
 #[test]
@@ -180,3 +180,631 @@ fn edges() {
     assert_eq!(gs.1.externals(Incoming).count(), 0);
     assert_eq!(gs.1.externals(Outgoing).count(), 0);
 }
+
+#[test]
+fn complement() {
+    let n = NodeIndex::new;
+    // 0 - 1 - 2 - 3 - 0
+    let mut g: UnGraph<u32, ()> =
+        Graph::from_edges([(n(0), n(1)), (n(1), n(2)), (n(2), n(3)), (n(3), n(0))]);
+    g.add_edge(n(1), n(0), ());
+    let c = g.complement();
+    assert_eq!(c.node_count(), 4);
+    assert_eq!(c.edge_count(), 2);
+    assert!(c.contains_edge(n(0), n(2)));
+    assert!(c.contains_edge(n(3), n(1)));
+    assert!(!c.contains_edge(n(0), n(1)));
+    assert_eq!(c.complement().edge_count(), 4);
+
+    let mut dg: DiGraph<u32, ()> =
+        Graph::from_edges([(n(0), n(1)), (n(1), n(2)), (n(2), n(3)), (n(3), n(0))]);
+    dg.add_edge(n(2), n(2), ());
+    let dc = dg.complement();
+    assert_eq!(dc.edge_count(), 4 * 3 - 4);
+    assert!(dc.contains_edge(n(1), n(0)));
+    assert!(!dc.contains_edge(n(0), n(1)));
+    assert!(!dc.contains_edge(n(2), n(2)));
+}
+
+#[test]
+fn to_dot() {
+    let mut g: UnGraph<&str, &str> = Graph::new_undirected();
+    let a = g.add_node("a \"quoted\"");
+    let b = g.add_node("b\\c");
+    g.add_edge(a, b, "line\nbreak");
+    g.add_edge(b, b, "loop");
+    assert_eq!(
+        g.to_dot_display(),
+        "graph G {\n\
+         \x20   0 [label=\"a \\\"quoted\\\"\"];\n\
+         \x20   1 [label=\"b\\\\c\"];\n\
+         \x20   0 -- 1 [label=\"line\\nbreak\"];\n\
+         \x20   1 -- 1 [label=\"loop\"];\n\
+         }\n"
+    );
+    let dot = g.into_edge_type::<Directed>().to_dot(
+        |i, _| format!("n{}", i.index()),
+        |i, _| format!("e{}", i.index()),
+    );
+    assert!(dot.starts_with("digraph G {\n"));
+    assert!(dot.contains("    1 [label=\"n1\"];\n"));
+    assert!(dot.contains("    1 -> 1 [label=\"e1\"];\n"));
+    assert!(dot.ends_with("}\n"));
+}
+
+#[test]
+fn reversed() {
+    let mut g: DiGraph<&str, u32> = Graph::new();
+    let a = g.add_node("a");
+    let b = g.add_node("b");
+    let e = g.add_edge(a, b, 1);
+    let r = g.reversed();
+    assert_eq!(r.edge_endpoints(e), Some((b, a)));
+    assert_eq!(g.edge_endpoints(e), Some((a, b)));
+    assert_eq!(r.neighbors(b).collect::<Vec<_>>(), vec![a]);
+    assert_eq!(r.neighbors(a).count(), 0);
+}
+
+#[test]
+fn to_undirected() {
+    let mut g: DiGraph<(), u32> = Graph::new();
+    let a = g.add_node(());
+    let b = g.add_node(());
+    let c = g.add_node(());
+    g.add_edge(a, b, 1);
+    g.add_edge(b, c, 2);
+    g.add_edge(b, a, 10);
+    g.add_edge(c, c, 3);
+    g.add_edge(a, b, 100);
+    let u = g.to_undirected(|x, y| x + y);
+    assert_eq!(u.node_count(), 3);
+    assert_eq!(u.edge_count(), 4);
+    let ab = EdgeIndex::new(0);
+    assert_eq!(u.edge_endpoints(ab), Some((a, b)));
+    assert_eq!(u[ab], 11);
+    let ab = u.find_edge(a, b).unwrap();
+    assert_eq!(u[ab], 100);
+    assert_eq!(u.find_edge_undirected(a, b).map(|(e, _)| e), Some(ab));
+    assert_eq!(u.find_edge_undirected(b, a).map(|(e, _)| e), Some(ab));
+    let bc = u.find_edge_undirected(c, b).unwrap().0;
+    assert_eq!(u.find_edge_undirected(b, c).unwrap().0, bc);
+    assert_eq!(u[bc], 2);
+    assert_eq!(u.edges(a).map(|e| *e.weight()).sum::<u32>(), 111);
+
+    let kept = g.into_edge_type::<Undirected>();
+    assert_eq!(kept.edge_count(), 5);
+}
+
+#[test]
+fn simple_graph_checks() {
+    let mut g: UnGraph<(), u32> = Graph::new_undirected();
+    let a = g.add_node(());
+    let b = g.add_node(());
+    let c = g.add_node(());
+    g.add_edge(a, b, 1);
+    g.add_edge(b, c, 2);
+    assert!(g.is_simple());
+    g.add_edge(b, a, 3);
+    g.add_edge(c, c, 4);
+    g.add_edge(a, b, 5);
+    g.add_edge(c, c, 6);
+    assert!(!g.is_simple());
+    assert_eq!(
+        g.self_loops().collect::<Vec<_>>(),
+        vec![EdgeIndex::new(3), EdgeIndex::new(5)]
+    );
+    assert_eq!(
+        g.parallel_edges().collect::<Vec<_>>(),
+        vec![
+            (EdgeIndex::new(0), EdgeIndex::new(2)),
+            (EdgeIndex::new(0), EdgeIndex::new(4)),
+            (EdgeIndex::new(3), EdgeIndex::new(5)),
+        ]
+    );
+
+    let mut h = g.clone();
+    assert_eq!(h.remove_self_loops(), 2);
+    assert_eq!(h.edge_count(), 4);
+    assert_eq!(h.self_loops().count(), 0);
+    assert_eq!(h.neighbors(c).collect::<Vec<_>>(), vec![b]);
+
+    assert_eq!(g.dedup_edges(|x, y| x + y), 3);
+    assert_eq!(g.edge_count(), 3);
+    assert_eq!(g[EdgeIndex::new(0)], 9);
+    assert_eq!(g.edge_endpoints(EdgeIndex::new(0)), Some((a, b)));
+    assert_eq!(g[EdgeIndex::new(1)], 2);
+    assert_eq!(g[EdgeIndex::new(2)], 10);
+    assert!(!g.is_simple());
+    assert_eq!(g.remove_self_loops(), 1);
+    assert!(g.is_simple());
+    assert_eq!(g.neighbors(b).count(), 2);
+
+    let mut dg: DiGraph<(), u32> = Graph::new();
+    let a = dg.add_node(());
+    let b = dg.add_node(());
+    dg.add_edge(a, b, 1);
+    dg.add_edge(b, a, 2);
+    assert!(dg.is_simple());
+    assert_eq!(dg.dedup_edges(|x, _| x), 0);
+}
+
+#[test]
+fn sort_nodes_and_edges() {
+    let mut g: DiGraph<u32, &str> = Graph::new();
+    let a = g.add_node(3);
+    let b = g.add_node(1);
+    let c = g.add_node(2);
+    let d = g.add_node(1);
+    g.add_edge(a, b, "ab");
+    g.add_edge(c, a, "ca");
+    g.add_edge(b, d, "bd");
+    g.add_edge(a, c, "ac");
+
+    let mapping = g.sort_nodes_by_key(|_, &data| data);
+    let n = NodeIndex::new;
+    assert_eq!(mapping, vec![n(3), n(0), n(2), n(1)]);
+    assert_eq!(
+        g.raw_nodes().iter().map(|n| n.data).collect::<Vec<_>>(),
+        vec![1, 1, 2, 3]
+    );
+    let a = mapping[a.index()];
+    assert_eq!(g[a], 3);
+    let mut out = g.neighbors(a).map(|v| g[v]).collect::<Vec<_>>();
+    out.sort();
+    assert_eq!(out, vec![1, 2]);
+    assert_eq!(g.find_edge(n(0), n(1)).map(|e| g[e]), Some("bd"));
+    assert_eq!(g.find_edge(n(2), n(3)).map(|e| g[e]), Some("ca"));
+
+    let edges_of_a = g.edges(a).map(|e| *e.weight()).collect::<Vec<_>>();
+    let mapping = g.sort_edges_by_key(|_, w| *w);
+    assert_eq!(
+        mapping,
+        vec![
+            EdgeIndex::new(0),
+            EdgeIndex::new(3),
+            EdgeIndex::new(2),
+            EdgeIndex::new(1)
+        ]
+    );
+    assert_eq!(
+        g.raw_edges().iter().map(|e| e.weight).collect::<Vec<_>>(),
+        vec!["ab", "ac", "bd", "ca"]
+    );
+    assert_eq!(
+        g.edges(a).map(|e| *e.weight()).collect::<Vec<_>>(),
+        edges_of_a
+    );
+    assert_eq!(
+        g.neighbors_directed(a, Incoming).collect::<Vec<_>>(),
+        vec![n(2)]
+    );
+    assert_eq!(g.remove_node(a), Some(3));
+    assert_eq!(g.edge_count(), 1);
+}
+
+#[test]
+fn edge_indices_double_ended() {
+    let mut g: DiGraph<(), ()> = Graph::new();
+    let a = g.add_node(());
+    for _ in 0..3 {
+        g.add_edge(a, a, ());
+    }
+    let mut edges = g.edge_indices();
+    assert_eq!(edges.len(), 3);
+    assert_eq!(edges.next_back(), Some(EdgeIndex::new(2)));
+    assert_eq!(edges.next(), Some(EdgeIndex::new(0)));
+    assert_eq!(edges.len(), 1);
+    assert_eq!(
+        g.edge_indices().rev().collect::<Vec<_>>(),
+        vec![EdgeIndex::new(2), EdgeIndex::new(1), EdgeIndex::new(0)]
+    );
+}
+
+#[test]
+fn matrices() {
+    let n = NodeIndex::new;
+    let mut g: DiGraph<(), f64> = Graph::new();
+    for _ in 0..3 {
+        g.add_node(());
+    }
+    g.add_edge(n(0), n(1), 1.0);
+    g.add_edge(n(0), n(1), 2.0);
+    g.add_edge(n(1), n(2), 4.0);
+    g.add_edge(n(2), n(2), 8.0);
+    assert_eq!(
+        g.to_adjacency_matrix(),
+        vec![0.0, 3.0, 0.0, 0.0, 0.0, 4.0, 0.0, 0.0, 8.0]
+    );
+    assert_eq!(
+        g.to_laplacian(),
+        vec![3.0, -3.0, 0.0, 0.0, 4.0, -4.0, 0.0, 0.0, 0.0]
+    );
+    assert_eq!(
+        g.to_incidence_matrix(),
+        vec![
+            -1.0, -1.0, 0.0, 0.0, //
+            1.0, 1.0, -1.0, 0.0, //
+            0.0, 0.0, 1.0, 0.0,
+        ]
+    );
+
+    let ug = g.to_undirected(|a, b| a + b);
+    assert_eq!(
+        ug.to_adjacency_matrix(),
+        vec![0.0, 3.0, 0.0, 3.0, 0.0, 4.0, 0.0, 4.0, 8.0]
+    );
+    assert_eq!(
+        ug.to_laplacian(),
+        vec![3.0, -3.0, 0.0, -3.0, 7.0, -4.0, 0.0, -4.0, 4.0]
+    );
+    assert_eq!(
+        ug.to_incidence_matrix(),
+        vec![
+            1.0, 1.0, 0.0, 0.0, //
+            1.0, 1.0, 1.0, 0.0, //
+            0.0, 0.0, 1.0, 2.0,
+        ]
+    );
+
+    let h: UnGraph<(), f64> = Graph::from_adjacency_matrix(3, &ug.to_adjacency_matrix());
+    assert_eq!(h.to_adjacency_matrix(), ug.to_adjacency_matrix());
+    assert_eq!(h.edge_count(), 3);
+    let h: DiGraph<(), f64> = Graph::from_adjacency_matrix(3, &g.to_adjacency_matrix());
+    assert_eq!(h.to_adjacency_matrix(), g.to_adjacency_matrix());
+    assert_eq!(h.edge_count(), 3);
+}
+
+#[test]
+fn neighbors_weighted() {
+    let n = NodeIndex::new;
+    let mut g: DiGraph<(), u32> = Graph::new();
+    for _ in 0..3 {
+        g.add_node(());
+    }
+    g.add_edge(n(0), n(1), 1);
+    g.add_edge(n(2), n(0), 2);
+    g.add_edge(n(0), n(0), 3);
+    g.add_edge(n(0), n(2), 4);
+    let out: Vec<_> = g.neighbors_weighted(n(0), Outgoing).collect();
+    assert_eq!(out, vec![(n(2), &4), (n(0), &3), (n(1), &1)]);
+    let inc: Vec<_> = g.neighbors_weighted(n(0), Incoming).collect();
+    assert_eq!(inc, vec![(n(0), &3), (n(2), &2)]);
+    for &dir in &[Outgoing, Incoming] {
+        let neighbors: Vec<_> = g.neighbors_weighted(n(0), dir).map(|(v, _)| v).collect();
+        assert_eq!(
+            neighbors,
+            g.neighbors_directed(n(0), dir).collect::<Vec<_>>()
+        );
+    }
+
+    let ug = g.into_edge_type::<Undirected>();
+    let all: Vec<_> = ug.neighbors_weighted(n(0), Incoming).collect();
+    assert_eq!(all, vec![(n(2), &4), (n(0), &3), (n(1), &1), (n(2), &2)]);
+    let neighbors: Vec<_> = all.iter().map(|&(v, _)| v).collect();
+    assert_eq!(neighbors, ug.neighbors(n(0)).collect::<Vec<_>>());
+    assert_eq!(ug.neighbors_weighted(n(5), Outgoing).count(), 0);
+}
+
+#[test]
+fn node_and_edge_weights() {
+    let mut g: UnGraph<&str, u32> = Graph::new_undirected();
+    let a = g.add_node("a");
+    let b = g.add_node("b");
+    let c = g.add_node("c");
+    g.add_edge(a, b, 1);
+    g.add_edge(c, b, 2);
+    assert_eq!(g.node_weights().collect::<Vec<_>>(), vec![&"a", &"b", &"c"]);
+    assert_eq!(g.node_weights().len(), 3);
+    assert_eq!(g.node_weights().next_back(), Some(&"c"));
+    assert_eq!(g.edge_weights().sum::<u32>(), 3);
+    assert_eq!(g.edge_weights().rev().collect::<Vec<_>>(), vec![&2, &1]);
+    for w in g.edge_weights_mut() {
+        *w *= 10;
+    }
+    assert_eq!(g.edge_weights().cloned().collect::<Vec<_>>(), vec![10, 20]);
+}
+
+#[test]
+fn node_indices_where() {
+    let n = NodeIndex::new;
+    let mut g: DiGraph<u32, ()> = Graph::new();
+    for w in &[4, 7, 10, 3, 8] {
+        g.add_node(*w);
+    }
+    let even: Vec<_> = g.node_indices_where(|w| w % 2 == 0).collect();
+    assert_eq!(even, vec![n(0), n(2), n(4)]);
+    assert_eq!(
+        g.node_indices_where(|&w| w > 5).rev().collect::<Vec<_>>(),
+        vec![n(4), n(2), n(1)]
+    );
+    assert_eq!(g.node_indices_where(|&w| w > 100).next(), None);
+}
+
+#[test]
+fn index_types() {
+    use std::collections::HashMap;
+
+    let mut g: Graph<&str, (), Directed, u64> = Graph::default();
+    let a = g.add_node("a");
+    let b = g.add_node("b");
+    let e = g.add_edge(a, b, ());
+    assert_eq!(g.find_edge(a, b), Some(e));
+    assert_eq!(NodeIndex::<u64>::end().index(), u64::MAX as usize);
+
+    assert_eq!(NodeIndex::from(1u64), b);
+    assert_eq!(EdgeIndex::from(0u64), e);
+    assert_eq!(usize::from(b), 1);
+    let i: usize = e.into();
+    assert_eq!(i, 0);
+
+    let mut names = HashMap::new();
+    for n in g.node_indices() {
+        names.insert(n, g[n]);
+    }
+    assert_eq!(names[&b], "b");
+}
+
+#[test]
+fn index_by_node_pair() {
+    let n = NodeIndex::new;
+    let mut g: DiGraph<(), u32> = Graph::new();
+    for _ in 0..3 {
+        g.add_node(());
+    }
+    g.add_edge(n(0), n(1), 5);
+    g.add_edge(n(1), n(2), 7);
+    assert_eq!(g[(n(0), n(1))], 5);
+    assert_eq!(g.edge_weight_between(n(1), n(0)), None);
+    g[(n(1), n(2))] += 1;
+    assert_eq!(g.edge_weight_between(n(1), n(2)), Some(&8));
+    if let Some(w) = g.edge_weight_between_mut(n(0), n(1)) {
+        *w = 1;
+    }
+    assert_eq!(g[(n(0), n(1))], 1);
+
+    let ug = g.into_edge_type::<Undirected>();
+    assert_eq!(ug[(n(1), n(0))], 1);
+    assert_eq!(ug[(n(2), n(1))], 8);
+    assert_eq!(ug.edge_weight_between(n(0), n(2)), None);
+}
+
+#[test]
+#[should_panic]
+fn index_by_missing_node_pair() {
+    let mut g: UnGraph<(), u32> = Graph::new_undirected();
+    let a = g.add_node(());
+    let b = g.add_node(());
+    let _ = g[(a, b)];
+}
+
+#[test]
+fn index_twice_mut() {
+    let mut g: DiGraph<u32, u32> = Graph::new();
+    let a = g.add_node(5);
+    let b = g.add_node(2);
+    let e = g.add_edge(a, b, 10);
+    let f = g.add_edge(b, a, 20);
+    {
+        let (x, y) = g.index_twice_mut(a, b);
+        *y += *x;
+        *x = 0;
+    }
+    {
+        let (x, y) = g.index_twice_mut(f, e);
+        std::mem::swap(x, y);
+    }
+    {
+        let (x, w) = g.index_twice_mut(a, e);
+        *x = *w;
+        *w = 1;
+    }
+    {
+        let (w, y) = g.index_twice_mut(EdgeIndex::new(0), NodeIndex::new(0));
+        *w += *y;
+    }
+    assert_eq!((g[a], g[b]), (20, 7));
+    assert_eq!((g[e], g[f]), (21, 10));
+}
+
+#[test]
+#[should_panic]
+fn index_twice_mut_aliasing() {
+    let mut g: UnGraph<u32, ()> = Graph::new_undirected();
+    let a = g.add_node(1);
+    let _ = g.index_twice_mut(a, a);
+}
+
+#[test]
+#[should_panic]
+fn index_twice_mut_edge_aliasing() {
+    let mut g: DiGraph<(), u32> = Graph::new();
+    let a = g.add_node(());
+    let e = g.add_edge(a, a, 1);
+    let _ = g.index_twice_mut(e, e);
+}
+
+#[test]
+fn isolated_nodes() {
+    let n = NodeIndex::new;
+    let mut g: DiGraph<(), ()> = Graph::new();
+    for _ in 0..5 {
+        g.add_node(());
+    }
+    // the chain 0 -> 1 -> 2, with 3 and 4 on their own
+    g.add_edge(n(0), n(1), ());
+    g.add_edge(n(1), n(2), ());
+    assert_eq!(g.isolated_nodes().collect::<Vec<_>>(), vec![n(3), n(4)]);
+    assert_eq!(g.source_nodes().collect::<Vec<_>>(), vec![n(0), n(3), n(4)]);
+    assert_eq!(g.sink_nodes().collect::<Vec<_>>(), vec![n(2), n(3), n(4)]);
+
+    g.add_edge(n(3), n(3), ());
+    assert_eq!(g.isolated_nodes().collect::<Vec<_>>(), vec![n(4)]);
+    let ug = g.into_edge_type::<Undirected>();
+    assert_eq!(
+        ug.isolated_nodes().collect::<Vec<_>>(),
+        ug.source_nodes().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn walk_edge_lists() {
+    let n = NodeIndex::new;
+    // more edges than nodes, so edge and node indices don't line up
+    let mut g: DiGraph<(), u32> = Graph::new();
+    for _ in 0..3 {
+        g.add_node(());
+    }
+    for (i, &(a, b)) in [(0, 1), (0, 2), (1, 0), (0, 0), (2, 0), (0, 1)]
+        .iter()
+        .enumerate()
+    {
+        g.add_edge(n(a), n(b), i as u32);
+    }
+    let walk = |g: &DiGraph<(), u32>, a, dir| {
+        let mut edges = Vec::new();
+        let mut next = g.first_edge(a, dir);
+        while let Some(e) = next {
+            edges.push(e);
+            next = g.next_edge(e, dir);
+        }
+        edges
+    };
+    for a in g.node_indices() {
+        for &dir in &[Outgoing, Incoming] {
+            let expected: Vec<_> = g.edges_directed(a, dir).map(|e| e.id()).collect();
+            assert_eq!(walk(&g, a, dir), expected);
+        }
+    }
+    assert_eq!(walk(&g, n(0), Outgoing).len(), 4);
+    assert_eq!(g.next_edge(EdgeIndex::new(6), Outgoing), None);
+    assert_eq!(g.next_edge(EdgeIndex::end(), Incoming), None);
+}
+
+#[test]
+#[should_panic(expected = "Graph::add_edge(): node a=0 b=2 out of bound (node_count=2)")]
+fn add_edge_out_of_bound() {
+    let mut g: DiGraph<(), ()> = Graph::new();
+    let a = g.add_node(());
+    g.add_node(());
+    g.add_edge(a, NodeIndex::new(2), ());
+}
+
+#[test]
+fn self_loop_neighbors_counted_once() {
+    let n = NodeIndex::new;
+    let mut g: DiGraph<(), ()> = Graph::new();
+    g.add_node(());
+    g.add_node(());
+    g.add_edge(n(0), n(0), ());
+    g.add_edge(n(0), n(1), ());
+    assert_eq!(g.neighbors(n(0)).collect::<Vec<_>>(), vec![n(1), n(0)]);
+    assert_eq!(
+        g.neighbors_directed(n(0), Incoming).collect::<Vec<_>>(),
+        vec![n(0)]
+    );
+    assert_eq!(
+        g.neighbors_undirected(n(0)).collect::<Vec<_>>(),
+        vec![n(1), n(0)]
+    );
+
+    let ug = g.into_edge_type::<Undirected>();
+    for &dir in &[Outgoing, Incoming] {
+        assert_eq!(
+            ug.neighbors_directed(n(0), dir).collect::<Vec<_>>(),
+            vec![n(1), n(0)]
+        );
+    }
+    assert_eq!(ug.neighbors(n(1)).collect::<Vec<_>>(), vec![n(0)]);
+}
+
+#[test]
+fn display() {
+    let mut g: DiGraph<&str, u32> = Graph::new();
+    let a = g.add_node("a");
+    let b = g.add_node("b");
+    g.add_edge(a, b, 7);
+    assert_eq!(
+        format!("{}", g),
+        "digraph G {\n    0 [label=\"a\"];\n    1 [label=\"b\"];\n    0 -> 1 [label=\"7\"];\n}\n"
+    );
+    let ug = g.into_edge_type::<Undirected>();
+    assert!(ug.to_string().starts_with("graph G {\n"));
+    assert!(ug.to_string().contains("    0 -- 1 [label=\"7\"];\n"));
+}
+
+#[test]
+fn graph_eq() {
+    let n = NodeIndex::new;
+    let mut g: DiGraph<&str, u32> = Graph::new();
+    let a = g.add_node("a");
+    let b = g.add_node("b");
+    g.add_edge(a, b, 1);
+    g.add_edge(b, b, 2);
+    let h = g.clone();
+    assert_eq!(g, h);
+
+    let mut k = h.clone();
+    k[n(1)] = "c";
+    assert_ne!(g, k);
+    let mut k = h.clone();
+    k.add_edge(a, a, 3);
+    assert_ne!(g, k);
+
+    // same edges, added in a different order
+    let mut k: DiGraph<&str, u32> = Graph::new();
+    k.add_node("a");
+    k.add_node("b");
+    k.add_edge(b, b, 2);
+    k.add_edge(a, b, 1);
+    assert_ne!(g, k);
+}
+
+#[test]
+fn graph_hash() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::HashSet;
+    use std::hash::{Hash, Hasher};
+
+    let hash = |g: &UnGraph<u8, u8>| {
+        let mut hasher = DefaultHasher::new();
+        g.hash(&mut hasher);
+        hasher.finish()
+    };
+    let mut g: UnGraph<u8, u8> = Graph::new_undirected();
+    let a = g.add_node(1);
+    let b = g.add_node(2);
+    g.add_edge(a, b, 3);
+    let mut h = g.clone();
+    assert_eq!(hash(&g), hash(&h));
+    h.add_edge(b, a, 3);
+    assert_ne!(hash(&g), hash(&h));
+
+    let mut set = HashSet::new();
+    set.insert(g.clone());
+    set.insert(h.clone());
+    set.insert(g.clone());
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&h));
+}
+
+#[test]
+fn iterate_node_references() {
+    let mut g: UnGraph<&str, ()> = Graph::new_undirected();
+    let a = g.add_node("a");
+    let b = g.add_node("b");
+    g.add_node("c");
+    g.add_edge(a, b, ());
+    let mut count = 0;
+    for (i, data) in &g {
+        assert_eq!(g.node_data(i), Some(data));
+        count += 1;
+    }
+    assert_eq!(count, g.node_count());
+    assert_eq!(g.node_references().len(), 3);
+    assert_eq!(g.node_indices().len(), 3);
+    assert_eq!(g.edge_references().len(), 1);
+    assert_eq!(
+        g.node_references().next_back(),
+        Some((NodeIndex::new(2), &"c"))
+    );
+}
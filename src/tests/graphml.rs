@@ -0,0 +1,123 @@
+#![allow(unused_imports)]
+
+use super::*;
+use graphml::*;
+
+#[cfg(test)]
+fn attrs(pairs: &[(&str, &str)]) -> Attributes {
+    pairs
+        .iter()
+        .map(|&(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn graphml_write() {
+    let mut g: DiGraph<&str, u32> = Graph::new();
+    let a = g.add_node("a<&>");
+    let b = g.add_node("b");
+    g.add_edge(a, b, 7);
+    let mut out = Vec::new();
+    write(&g, &mut out).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n\
+         \x20 <key id=\"n0\" for=\"node\" attr.name=\"label\" attr.type=\"string\"/>\n\
+         \x20 <key id=\"e0\" for=\"edge\" attr.name=\"label\" attr.type=\"string\"/>\n\
+         \x20 <graph id=\"G\" edgedefault=\"directed\">\n\
+         \x20   <node id=\"n0\">\n\
+         \x20     <data key=\"n0\">a&lt;&amp;&gt;</data>\n\
+         \x20   </node>\n\
+         \x20   <node id=\"n1\">\n\
+         \x20     <data key=\"n0\">b</data>\n\
+         \x20   </node>\n\
+         \x20   <edge id=\"e0\" source=\"n0\" target=\"n1\">\n\
+         \x20     <data key=\"e0\">7</data>\n\
+         \x20   </edge>\n\
+         \x20 </graph>\n\
+         </graphml>\n"
+    );
+}
+
+#[test]
+fn graphml_round_trip() {
+    let mut g: UnGraph<Attributes, Attributes> = Graph::new_undirected();
+    let a = g.add_node(attrs(&[("name", "a \"x\""), ("color", "red")]));
+    let b = g.add_node(attrs(&[("name", "b")]));
+    let c = g.add_node(Attributes::new());
+    g.add_edge(a, b, attrs(&[("weight", "1.5")]));
+    g.add_edge(c, c, Attributes::new());
+
+    let mut out = Vec::new();
+    write_with(&g, &mut out, |n| n.clone(), |e| e.clone()).unwrap();
+    let h: UnGraph<Attributes, Attributes> = read(&out[..]).unwrap();
+    assert_eq!(h.node_count(), 3);
+    for (n, m) in g.raw_nodes().iter().zip(h.raw_nodes()) {
+        assert_eq!(n.data, m.data);
+    }
+    for (e, f) in g.raw_edges().iter().zip(h.raw_edges()) {
+        assert_eq!(e.weight, f.weight);
+        assert_eq!((e.source(), e.target()), (f.source(), f.target()));
+    }
+}
+
+#[test]
+fn graphml_read() {
+    let input = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!-- written by hand -->
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns"
+         xmlns:y="http://www.yworks.com/xml/graphml">
+  <key id="d0" for="node" attr.name="color" attr.type="string">
+    <default>yellow</default>
+  </key>
+  <key id="d1" for="edge" attr.name="weight" attr.type="double"/>
+  <graph id="G" edgedefault="directed">
+    <edge source="x" target="y"><data key="d1">1.0</data></edge>
+    <node id="x"><data key="d0"><![CDATA[<green>]]></data></node>
+    <node id="y"/>
+    <edge source="y" target="x" directed="true"/>
+  </graph>
+</graphml>"#;
+    let g: DiGraph<Attributes, Attributes> = read(input.as_bytes()).unwrap();
+    assert_eq!(g.node_count(), 2);
+    assert_eq!(g.raw_nodes()[0].data, attrs(&[("color", "<green>")]));
+    assert_eq!(g.raw_nodes()[1].data, attrs(&[("color", "yellow")]));
+    assert_eq!(g.edge_count(), 2);
+    assert_eq!(g.raw_edges()[0].weight, attrs(&[("weight", "1.0")]));
+    assert_eq!(g.raw_edges()[1].source(), NodeIndex::new(1));
+}
+
+#[test]
+fn graphml_read_quoted_gt() {
+    let input = r#"<graphml>
+  <key id="d0" for="node" attr.name="color"/>
+  <graph edgedefault="directed">
+    <node id="a>b"><data key="d0" note="x > y">red</data></node>
+    <node id='c>d'/>
+    <edge source="a>b" target='c>d'/>
+  </graph>
+</graphml>"#;
+    let g: DiGraph<Attributes, Attributes> = read(input.as_bytes()).unwrap();
+    assert_eq!(g.node_count(), 2);
+    assert_eq!(g.raw_nodes()[0].data, attrs(&[("color", "red")]));
+    assert_eq!(g.edge_count(), 1);
+    assert_eq!(g.raw_edges()[0].target(), NodeIndex::new(1));
+}
+
+#[test]
+fn graphml_errors() {
+    let err = |input: &str| match read::<Directed, _>(input.as_bytes()) {
+        Err(GraphMlError::Parse { line, .. }) => line,
+        _ => panic!("expected a parse error"),
+    };
+    assert_eq!(err("<graphml>\n<graph edgedefault=\"undirected\">"), 2);
+    assert_eq!(
+        err("<graphml><graph>\n<edge source=\"a\" target=\"b\"/></graph></graphml>"),
+        2
+    );
+    assert_eq!(err("<graphml><graph>\n\n<node/></graph></graphml>"), 3);
+    assert_eq!(err("<graphml><graph></node></graphml>"), 1);
+    assert_eq!(err("<graphml>"), 1);
+    assert_eq!(err("<graphml><graph>\n<node id=\"a>"), 2);
+}
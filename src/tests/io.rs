@@ -0,0 +1,281 @@
+#![allow(unused_imports)]
+
+use super::*;
+use io::graph6::{self, Graph6Error};
+use io::matrix_market::{self, MatrixMarketGraph};
+use io::{pajek, ReadError};
+
+#[cfg(test)]
+fn error_line(result: Result<impl Sized, ReadError>) -> usize {
+    match result {
+        Err(ReadError::Parse { line, .. }) => line,
+        _ => panic!("expected a parse error"),
+    }
+}
+
+#[test]
+fn pajek_round_trip() {
+    let mut g: DiGraph<String, f64> = Graph::new();
+    let a = g.add_node("a".to_string());
+    let b = g.add_node("node b".to_string());
+    g.add_edge(a, b, 1.5);
+    g.add_edge(b, b, -2.0);
+
+    let mut out = Vec::new();
+    pajek::write(&g, &mut out).unwrap();
+    assert_eq!(
+        String::from_utf8(out.clone()).unwrap(),
+        "*Vertices 2\n1 \"a\"\n2 \"node b\"\n*Arcs\n1 2 1.5\n2 2 -2\n"
+    );
+    let h: DiGraph<String, f64> = pajek::read(&out[..]).unwrap();
+    assert_eq!(h[a], "a");
+    assert_eq!(h[b], "node b");
+    for (e, f) in g.raw_edges().iter().zip(h.raw_edges()) {
+        assert_eq!((e.source(), e.target()), (f.source(), f.target()));
+        assert_eq!(e.weight, f.weight);
+    }
+}
+
+#[test]
+fn pajek_labels() {
+    let mut g: UnGraph<String, f64> = Graph::new_undirected();
+    let a = g.add_node("it's 100% \\ *odd*".to_string());
+    g.add_edge(a, a, 0.5);
+    let mut out = Vec::new();
+    pajek::write(&g, &mut out).unwrap();
+    let h: UnGraph<String, f64> = pajek::read(&out[..]).unwrap();
+    assert_eq!(h[a], g[a]);
+
+    for label in &["say \"hi\"", "two\nlines", "carriage\rreturn"] {
+        g[a] = label.to_string();
+        let err = pajek::write(&g, Vec::new()).unwrap_err();
+        assert_eq!(err.kind(), ::std::io::ErrorKind::InvalidData);
+    }
+
+    let mut g: DiGraph<&str, &str> = Graph::new();
+    let a = g.add_node("a");
+    g.add_edge(a, a, "1 2");
+    let err = pajek::write(&g, Vec::new()).unwrap_err();
+    assert_eq!(err.kind(), ::std::io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn pajek_read() {
+    let input = "% comment\n\
+                 *Network test\n\
+                 *Vertices 3\n\
+                 1 first 0.1 0.2\n\
+                 3 \"third one\"\n\
+                 *Edges\n\
+                 1 2\n\
+                 \n\
+                 2 3 4\n";
+    let g: UnGraph<String, f64> = pajek::read(input.as_bytes()).unwrap();
+    let data: Vec<_> = g.raw_nodes().iter().map(|n| &n.data[..]).collect();
+    assert_eq!(data, ["first", "2", "third one"]);
+    let weights: Vec<_> = g.raw_edges().iter().map(|e| e.weight).collect();
+    assert_eq!(weights, [1.0, 4.0]);
+
+    let read = |s: &str| pajek::read::<Directed, _>(s.as_bytes());
+    assert_eq!(error_line(read("*Vertices 2\n*Edges\n1 2")), 2);
+    assert_eq!(error_line(read("*Vertices 2\n*Arcs\n\n1 3")), 4);
+    assert_eq!(error_line(read("*Vertices 2\n*Arcs\n1 2 x")), 3);
+    assert_eq!(error_line(read("1 2")), 1);
+
+    // huge vertex counts are only allocated once the input checks out
+    assert_eq!(error_line(read("*Vertices 4000000000\n*Arcs\n1 2 x")), 3);
+    assert_eq!(error_line(read("*Vertices 4000000000\n*Edges\n")), 2);
+    assert_eq!(error_line(read("*Vertices 40000000000\n")), 1);
+}
+
+#[test]
+fn matrix_market_fixture() {
+    let input = include_str!("fixtures/small.mtx");
+    let g = match matrix_market::read(input.as_bytes()).unwrap() {
+        MatrixMarketGraph::Undirected(g) => g,
+        MatrixMarketGraph::Directed(_) => panic!("expected an undirected graph"),
+    };
+    assert_eq!(g.node_count(), 4);
+    assert_eq!(g.edge_count(), 4);
+    let n = NodeIndex::new;
+    let edges: Vec<_> = g
+        .raw_edges()
+        .iter()
+        .map(|e| (e.source(), e.target(), e.weight))
+        .collect();
+    assert_eq!(
+        edges,
+        [
+            (n(1), n(0), 0.5),
+            (n(2), n(0), -1.25),
+            (n(2), n(1), 2.0),
+            (n(3), n(3), 1000.0)
+        ]
+    );
+    assert_eq!(g.neighbors(n(0)).count(), 2);
+}
+
+#[test]
+fn matrix_market_general() {
+    let input = "%%MatrixMarket matrix coordinate pattern general\n2 3 2\n1 3\n2 1\n";
+    let g = match matrix_market::read(input.as_bytes()).unwrap() {
+        MatrixMarketGraph::Directed(g) => g,
+        MatrixMarketGraph::Undirected(_) => panic!("expected a directed graph"),
+    };
+    assert_eq!(g.node_count(), 3);
+    assert_eq!(
+        g.find_edge(NodeIndex::new(0), NodeIndex::new(2)),
+        Some(EdgeIndex::new(0))
+    );
+    assert_eq!(g.raw_edges()[1].weight, 1.0);
+
+    let read = |s: &str| matrix_market::read(s.as_bytes()).map(|_| ());
+    let header = "%%MatrixMarket matrix coordinate real general\n";
+    assert_eq!(
+        error_line(read("%%MatrixMarket matrix array real general")),
+        1
+    );
+    assert_eq!(error_line(read(&format!("{}2 2 1\n3 1 1.0", header))), 3);
+    assert_eq!(error_line(read(&format!("{}2 2 1\n1 1", header))), 3);
+    assert_eq!(error_line(read(&format!("{}2 2 2\n1 1 1\n%\n", header))), 4);
+
+    // huge sizes are only allocated once all entries are read
+    assert_eq!(
+        error_line(read(&format!("{}4000000000 1 2\n1 1 1\n", header))),
+        3
+    );
+    assert_eq!(
+        error_line(read(&format!("{}4000000000 1 1\n5000000000 1 1\n", header))),
+        3
+    );
+}
+
+/// A pseudo-random undirected graph, simple unless `multi` is set.
+#[cfg(test)]
+fn random_graph(n: usize, seed: u64, multi: bool) -> UnGraph<(), ()> {
+    let mut state = seed;
+    let mut next = move || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (state >> 33) as usize
+    };
+    let mut g = Graph::new_undirected();
+    for _ in 0..n {
+        g.add_node(());
+    }
+    if n == 0 {
+        return g;
+    }
+    for _ in 0..next() % (2 * n + 1) {
+        let (a, b) = (NodeIndex::new(next() % n), NodeIndex::new(next() % n));
+        if multi || (a != b && g.find_edge(a, b).is_none()) {
+            g.add_edge(a, b, ());
+        }
+    }
+    g
+}
+
+#[cfg(test)]
+fn sorted_edges(g: &UnGraph<(), ()>) -> Vec<(usize, usize)> {
+    let mut edges: Vec<_> = g
+        .raw_edges()
+        .iter()
+        .map(|e| {
+            let (a, b) = (e.source().index(), e.target().index());
+            (a.min(b), a.max(b))
+        })
+        .collect();
+    edges.sort();
+    edges
+}
+
+#[test]
+fn graph6_examples() {
+    let n = NodeIndex::new;
+    let mut g: UnGraph<(), ()> = Graph::new_undirected();
+    for _ in 0..5 {
+        g.add_node(());
+    }
+    for &(a, b) in &[(0, 2), (0, 4), (1, 3), (3, 4)] {
+        g.add_edge(n(a), n(b), ());
+    }
+    // the example of the specification
+    assert_eq!(graph6::encode(&g), "DQc");
+    assert_eq!(
+        sorted_edges(&graph6::decode(">>graph6<<DQc\n").unwrap()),
+        sorted_edges(&g)
+    );
+
+    let mut g: UnGraph<(), ()> = Graph::new_undirected();
+    for _ in 0..7 {
+        g.add_node(());
+    }
+    for &(a, b) in &[(0, 1), (0, 2), (1, 2), (5, 6)] {
+        g.add_edge(n(a), n(b), ());
+    }
+    assert_eq!(graph6::encode_sparse6(&g), ":Fa@x^");
+    assert_eq!(
+        sorted_edges(&graph6::decode(":Fa@x^").unwrap()),
+        sorted_edges(&g)
+    );
+
+    assert_eq!(graph6::decode("").err(), Some(Graph6Error::UnexpectedEnd));
+    assert_eq!(graph6::decode("DQ").err(), Some(Graph6Error::UnexpectedEnd));
+    assert_eq!(
+        graph6::decode("DQcc").err(),
+        Some(Graph6Error::TrailingData)
+    );
+    assert_eq!(
+        graph6::decode("D c").err(),
+        Some(Graph6Error::InvalidByte {
+            position: 1,
+            byte: b' '
+        })
+    );
+    assert!(graph6::decode("&Bg").is_err());
+    // headers for 2^36 - 1 nodes
+    let too_large = Some(Graph6Error::TooLarge((1 << 36) - 1));
+    assert_eq!(graph6::decode("~~~~~~~~").err(), too_large);
+    assert_eq!(graph6::decode(":~~~~~~~~").err(), too_large);
+    // 2^24 nodes, without the edge data
+    assert_eq!(
+        graph6::decode("~~?@????").err(),
+        Some(Graph6Error::UnexpectedEnd)
+    );
+}
+
+#[test]
+fn graph6_round_trip() {
+    for n in (0..20).chain(vec![62, 63, 64, 100]) {
+        for seed in 0..10 {
+            let g = random_graph(n, seed, false);
+            let encoded = graph6::encode(&g);
+            let h = graph6::decode(&encoded).unwrap();
+            assert_eq!(h.node_count(), n);
+            assert_eq!(sorted_edges(&h), sorted_edges(&g), "{}", encoded);
+
+            let g = random_graph(n, seed, true);
+            let encoded = graph6::encode_sparse6(&g);
+            let h = graph6::decode(&encoded).unwrap();
+            assert_eq!(h.node_count(), n);
+            assert_eq!(sorted_edges(&h), sorted_edges(&g), "{}", encoded);
+        }
+    }
+}
+
+#[test]
+fn sparse6_large() {
+    for &n in &[258_047, 258_048] {
+        let mut g: UnGraph<(), ()> = Graph::with_capacity(n, 1);
+        for _ in 0..n {
+            g.add_node(());
+        }
+        g.add_edge(NodeIndex::new(0), NodeIndex::new(n - 1), ());
+        let encoded = graph6::encode_sparse6(&g);
+        assert_eq!(&encoded[..3], if n < 258_048 { ":~}" } else { ":~~" });
+        let h = graph6::decode(&encoded).unwrap();
+        assert_eq!(h.node_count(), n);
+        assert_eq!(sorted_edges(&h), vec![(0, n - 1)]);
+    }
+}
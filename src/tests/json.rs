@@ -0,0 +1,61 @@
+#![allow(unused_imports)]
+
+use super::*;
+use io::json::*;
+use serde_json::Value;
+
+#[test]
+fn node_link_round_trip() {
+    let mut g: UnGraph<String, f64> = Graph::new_undirected();
+    let a = g.add_node("a".to_string());
+    let b = g.add_node("b".to_string());
+    g.add_edge(a, b, 1.5);
+    g.add_edge(b, b, 2.0);
+
+    let value = to_node_link_with(&g, |_, name| Value::from(name.clone())).unwrap();
+    assert_eq!(
+        value.to_string(),
+        "{\"directed\":false,\
+         \"links\":[{\"source\":\"a\",\"target\":\"b\",\"weight\":1.5},\
+         {\"source\":\"b\",\"target\":\"b\",\"weight\":2.0}],\
+         \"nodes\":[{\"data\":\"a\",\"id\":\"a\"},{\"data\":\"b\",\"id\":\"b\"}]}"
+    );
+    let h: UnGraph<String, f64> = from_node_link(value).unwrap();
+    assert_eq!(h[a], "a");
+    assert_eq!(h[b], "b");
+    for (e, f) in g.raw_edges().iter().zip(h.raw_edges()) {
+        assert_eq!((e.source(), e.target()), (f.source(), f.target()));
+        assert_eq!(e.weight, f.weight);
+    }
+
+    let h: UnGraph<String, f64> = from_node_link(to_node_link(&g).unwrap()).unwrap();
+    assert_eq!(h.edge_count(), 2);
+}
+
+#[test]
+fn node_link_errors() {
+    let parse = |s: &str| from_node_link::<(), (), Directed, DefaultIx>(s.parse().unwrap());
+    assert!(parse("{\"nodes\":[{\"id\":1}],\"links\":[]}").is_ok());
+    assert!(parse("{\"directed\":false,\"nodes\":[],\"links\":[]}").is_err());
+    assert!(parse("{\"nodes\":[{\"id\":1}],\"links\":[{\"source\":1,\"target\":2}]}").is_err());
+    assert!(parse("{\"nodes\":[{\"id\":1},{\"id\":1}],\"links\":[]}").is_err());
+    assert!(parse("{\"nodes\":[{\"id\":1,\"data\":5}],\"links\":[]}").is_err());
+    assert!(parse("{\"nodes\":[]}").is_err());
+}
+
+#[test]
+fn node_link_unserializable() {
+    use std::collections::BTreeMap;
+
+    // JSON object keys must be strings
+    let mut data = BTreeMap::new();
+    data.insert((1, 2), "pair");
+    let mut g: DiGraph<BTreeMap<(u8, u8), &str>, ()> = Graph::new();
+    g.add_node(data.clone());
+    assert!(to_node_link(&g).is_err());
+
+    let mut g: DiGraph<(), BTreeMap<(u8, u8), &str>> = Graph::new();
+    let a = g.add_node(());
+    g.add_edge(a, a, data);
+    assert!(to_node_link_with(&g, |i, _| Value::from(i.index())).is_err());
+}
@@ -1,3 +1,26 @@
+pub mod algo;
+#[cfg(all(test, feature = "quickcheck"))]
+pub mod arbitrary;
+#[cfg(all(test, feature = "bin"))]
+pub mod binary;
+pub mod dot;
+#[cfg(test)]
+pub mod generators;
+#[allow(
+    clippy::bool_assert_comparison,
+    clippy::legacy_numeric_constants,
+    clippy::option_map_unit_fn
+)]
 pub mod graph;
+pub mod graphml;
+pub mod io;
+#[cfg(all(test, feature = "json"))]
+pub mod json;
+pub mod operator;
+#[cfg(all(test, feature = "rayon"))]
+pub mod parallel;
+#[cfg(all(test, feature = "serde"))]
+pub mod serialization;
+pub mod stable_graph;
 
 use graph::*;
@@ -0,0 +1,99 @@
+#![allow(unused_imports)]
+
+use super::Direction::{Incoming, Outgoing};
+use super::*;
+use operator::*;
+
+#[test]
+fn append() {
+    let n = NodeIndex::new;
+    let mut g: DiGraph<&str, u32> = Graph::new();
+    let a = g.add_node("a");
+    let b = g.add_node("b");
+    g.add_edge(a, b, 1);
+
+    let mut h: DiGraph<&str, u32> = Graph::new();
+    let c = h.add_node("c");
+    let d = h.add_node("d");
+    h.add_edge(c, d, 2);
+    h.add_edge(c, d, 3);
+    h.add_edge(d, d, 4);
+
+    assert_eq!(g.append(h), 2..4);
+    assert_eq!(g.node_count(), 4);
+    assert_eq!(g.edge_count(), 4);
+    assert_eq!(g[n(2)], "c");
+    assert_eq!(g.edges(n(2)).count(), 2);
+    assert_eq!(g.neighbors_directed(n(3), Incoming).count(), 3);
+    assert_eq!(g.find_edge(n(3), n(3)), Some(EdgeIndex::new(3)));
+    assert_eq!(g.find_edge(a, b), Some(EdgeIndex::new(0)));
+    assert_eq!(g.find_edge(b, n(2)), None);
+    assert_eq!(g.remove_node(n(2)), Some("c"));
+    assert_eq!(g.edge_count(), 2);
+}
+
+#[test]
+fn disjoint_union_copies_both() {
+    let mut g: UnGraph<u32, ()> = Graph::new_undirected();
+    let a = g.add_node(0);
+    let b = g.add_node(1);
+    g.add_edge(a, b, ());
+
+    let (u, offset) = disjoint_union(&g, &g);
+    assert_eq!(offset, 2);
+    assert_eq!(u.node_count(), 4);
+    assert_eq!(u.edge_count(), 2);
+    assert!(u.contains_edge(NodeIndex::new(3), NodeIndex::new(2)));
+    assert!(!u.contains_edge(a, NodeIndex::new(3)));
+    assert_eq!(g.node_count(), 2);
+}
+
+#[cfg(test)]
+fn path(n: usize) -> UnGraph<usize, ()> {
+    let mut g = Graph::new_undirected();
+    for i in 0..n {
+        let v = g.add_node(i);
+        if i > 0 {
+            g.add_edge(NodeIndex::new(i - 1), v, ());
+        }
+    }
+    g
+}
+
+#[test]
+fn cartesian_product_of_paths_is_grid() {
+    let g = cartesian_product(&path(10), &path(10));
+    assert_eq!(g.node_count(), 100);
+    assert_eq!(g.edge_count(), 2 * 10 * 9);
+    // node (3, 7) is at index 37
+    let v = NodeIndex::new(37);
+    assert_eq!(g[v], (3, 7));
+    let mut neighbors = g.neighbors(v).map(|n| g[n]).collect::<Vec<_>>();
+    neighbors.sort();
+    assert_eq!(neighbors, vec![(2, 7), (3, 6), (3, 8), (4, 7)]);
+    let e = g.find_edge(NodeIndex::new(37), NodeIndex::new(47)).unwrap();
+    assert_eq!(g[e], ProductEdge::First(()));
+    let e = g.find_edge(NodeIndex::new(37), NodeIndex::new(38)).unwrap();
+    assert_eq!(g[e], ProductEdge::Second(()));
+    assert_eq!(g.neighbors(NodeIndex::new(0)).count(), 2);
+}
+
+#[test]
+fn tensor_product_of_edges() {
+    let n = NodeIndex::new;
+    let g = tensor_product(&path(2), &path(2));
+    assert_eq!(g.node_count(), 4);
+    assert_eq!(g.edge_count(), 2);
+    assert!(g.contains_edge(n(0), n(3)));
+    assert!(g.contains_edge(n(1), n(2)));
+    assert!(!g.contains_edge(n(0), n(1)));
+
+    let mut dg: DiGraph<(), u8> = Graph::new();
+    let a = dg.add_node(());
+    let b = dg.add_node(());
+    dg.add_edge(a, b, 1);
+    let t = tensor_product(&dg, &dg);
+    assert_eq!(t.edge_count(), 1);
+    let e = t.find_edge(n(0), n(3)).unwrap();
+    assert_eq!(t[e], ProductEdge::Both(1, 1));
+}
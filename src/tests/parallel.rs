@@ -0,0 +1,25 @@
+#![allow(unused_imports)]
+
+use super::Direction::{Incoming, Outgoing};
+use super::*;
+use rayon::prelude::*;
+
+#[test]
+fn par_weights() {
+    let mut g: UnGraph<u64, u64> = Graph::new_undirected();
+    for i in 0..1000 {
+        g.add_node(i);
+    }
+    for i in 0..999 {
+        g.add_edge(NodeIndex::new(i), NodeIndex::new(i + 1), i as u64);
+    }
+
+    g.par_node_weights_mut().for_each(|w| *w *= 2);
+    g.par_edge_weights_mut().for_each(|w| *w *= 2);
+    assert!(g.node_indices().all(|a| g[a] == 2 * a.index() as u64));
+    assert!(g.edge_indices().all(|e| g[e] == 2 * e.index() as u64));
+
+    assert_eq!(g.par_node_weights().sum::<u64>(), 999 * 1000);
+    assert_eq!(g.par_edge_weights().sum::<u64>(), 998 * 999);
+    assert_eq!(g.par_node_weights().count(), g.node_count());
+}
@@ -0,0 +1,48 @@
+#![allow(unused_imports)]
+
+use super::Direction::{Incoming, Outgoing};
+use super::*;
+use serde_json;
+
+#[test]
+fn round_trip() {
+    let mut g: DiGraph<String, u32> = Graph::new();
+    let a = g.add_node("a".to_string());
+    let b = g.add_node("b".to_string());
+    let c = g.add_node("c".to_string());
+    g.add_edge(a, b, 1);
+    g.add_edge(b, c, 2);
+    g.add_edge(c, c, 3);
+    g.add_edge(a, b, 4);
+
+    let json = serde_json::to_string(&g).unwrap();
+    assert_eq!(
+        json,
+        "{\"nodes\":[\"a\",\"b\",\"c\"],\"edges\":[\
+         {\"source\":0,\"target\":1,\"weight\":1},\
+         {\"source\":1,\"target\":2,\"weight\":2},\
+         {\"source\":2,\"target\":2,\"weight\":3},\
+         {\"source\":0,\"target\":1,\"weight\":4}]}"
+    );
+
+    let h: DiGraph<String, u32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        h.raw_nodes().iter().map(|n| &n.data).collect::<Vec<_>>(),
+        g.raw_nodes().iter().map(|n| &n.data).collect::<Vec<_>>()
+    );
+    for (e, f) in g.raw_edges().iter().zip(h.raw_edges()) {
+        assert_eq!(e.source().index(), f.source().index());
+        assert_eq!(e.target().index(), f.target().index());
+        assert_eq!(e.weight, f.weight);
+        assert_eq!(e.next_edge(Outgoing).index(), f.next_edge(Outgoing).index());
+        assert_eq!(e.next_edge(Incoming).index(), f.next_edge(Incoming).index());
+    }
+    assert_eq!(h.edge_count(), 4);
+}
+
+#[test]
+fn invalid_endpoint() {
+    let json = "{\"nodes\":[null],\"edges\":[{\"source\":0,\"target\":1,\"weight\":null}]}";
+    let res: Result<UnGraph<(), ()>, _> = serde_json::from_str(json);
+    assert!(res.is_err());
+}
@@ -0,0 +1,83 @@
+#![allow(unused_imports)]
+
+use super::Direction::{Incoming, Outgoing};
+use super::*;
+use stable_graph::*;
+
+#[test]
+fn empty_stable_graph() {
+    let g: StableDiGraph<&str, usize> = StableGraph::new();
+    assert_eq!(g.node_count(), 0);
+    assert_eq!(g.edge_count(), 0);
+    assert!(g.is_directed());
+    assert_eq!(g.node_data(NodeIndex::new(0)), None);
+    assert_eq!(g.edge_weight(EdgeIndex::new(0)), None);
+    assert_eq!(g.neighbors(NodeIndex::new(0)).count(), 0);
+    assert_eq!(g.node_indices().count(), 0);
+    let ug: StableUnGraph<&str, usize> = StableGraph::new_undirected();
+    assert!(!ug.is_directed());
+}
+
+#[test]
+fn remove_node_keeps_indices() {
+    let mut g: StableDiGraph<&str, usize> = StableGraph::new();
+    let ns: Vec<_> = ["a", "b", "c", "d", "e"]
+        .iter()
+        .map(|&d| g.add_node(d))
+        .collect();
+    g.add_edge(ns[0], ns[2], 1);
+    g.add_edge(ns[2], ns[4], 2);
+    let e = g.add_edge(ns[3], ns[4], 3);
+    g.add_edge(ns[1], ns[2], 4);
+
+    assert_eq!(g[NodeIndex::new(4)], "e");
+    assert_eq!(g.remove_node(NodeIndex::new(2)), Some("c"));
+    assert_eq!(g[NodeIndex::new(4)], "e");
+    assert_eq!(g.node_data(NodeIndex::new(2)), None);
+    assert_eq!(g.remove_node(NodeIndex::new(2)), None);
+
+    assert_eq!(g.node_count(), 4);
+    assert_eq!(g.edge_count(), 1);
+    assert_eq!(g[e], 3);
+    assert_eq!(g.edge_endpoints(e), Some((ns[3], ns[4])));
+    assert_eq!(
+        g.node_indices().collect::<Vec<_>>(),
+        vec![ns[0], ns[1], ns[3], ns[4]]
+    );
+    assert_eq!(g.edge_indices().collect::<Vec<_>>(), vec![e]);
+    assert_eq!(g.neighbors(ns[0]).count(), 0);
+    assert_eq!(
+        g.neighbors_directed(ns[4], Incoming).collect::<Vec<_>>(),
+        vec![ns[3]]
+    );
+}
+
+#[test]
+fn remove_edge_keeps_indices() {
+    let mut g: StableUnGraph<(), usize> = StableGraph::new_undirected();
+    let a = g.add_node(());
+    let b = g.add_node(());
+    let c = g.add_node(());
+    let e1 = g.add_edge(a, b, 1);
+    let e2 = g.add_edge(b, c, 2);
+    let e3 = g.add_edge(c, a, 3);
+    let loop_e = g.add_edge(b, b, 4);
+
+    assert_eq!(g.remove_edge(e2), Some(2));
+    assert_eq!(g.remove_edge(e2), None);
+    assert_eq!(g.edge_count(), 3);
+    assert_eq!(g[e1], 1);
+    assert_eq!(g[e3], 3);
+    assert_eq!(g.find_edge(c, b), None);
+    assert_eq!(g.find_edge(a, c), Some(e3));
+    assert_eq!(g.find_edge(b, b), Some(loop_e));
+
+    let mut neighbors = g.neighbors(b).collect::<Vec<_>>();
+    neighbors.sort();
+    assert_eq!(neighbors, vec![a, b]);
+
+    assert_eq!(g.remove_node(b), Some(()));
+    assert_eq!(g.edge_count(), 1);
+    assert_eq!(g.neighbors(a).collect::<Vec<_>>(), vec![c]);
+    assert_eq!(g.neighbors_directed(a, Outgoing).count(), 1);
+}